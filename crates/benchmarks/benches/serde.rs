@@ -1,7 +1,11 @@
 mod common;
 
-use criterion::{black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use criterion::{
+    black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, SamplingMode,
+    Throughput,
+};
 use hcl::{Body, Value};
+use std::fmt::Write;
 
 fn ser(c: &mut Criterion) {
     let tests = testdata::load().unwrap();
@@ -51,10 +55,65 @@ fn de(c: &mut Criterion) {
                 BatchSize::SmallInput,
             )
         });
+
+        group.bench_function(BenchmarkId::new("reader", test.name()), |b| {
+            hcl::from_reader::<Value, _>(test.input.as_bytes()).unwrap();
+            b.iter(|| black_box(hcl::from_reader::<Value, _>(test.input.as_bytes()).unwrap()))
+        });
+    });
+
+    group.finish();
+}
+
+fn de_large(c: &mut Criterion) {
+    let input = large_input(10 * 1024 * 1024);
+
+    let mut group = c.benchmark_group("de_large");
+    group.sampling_mode(SamplingMode::Flat);
+    group.sample_size(10);
+    group.throughput(Throughput::Bytes(input.len() as u64));
+
+    group.bench_function("str", |b| {
+        b.iter(|| black_box(hcl::from_str::<Value>(&input).unwrap()))
+    });
+
+    group.bench_function("reader", |b| {
+        b.iter(|| black_box(hcl::from_reader::<Value, _>(input.as_bytes()).unwrap()))
     });
 
     group.finish();
 }
 
-criterion_group!(benches, ser, de);
+// Generates an input of at least `size` bytes consisting of blocks with distinct labels.
+fn large_input(size: usize) -> String {
+    let mut input = String::with_capacity(size);
+    let mut i = 0;
+
+    while input.len() < size {
+        write!(
+            input,
+            r#"
+resource "aws_instance" "web_{i}" {{
+  ami           = "ami-{i:08x}"
+  instance_type = "t3.micro"
+  count         = {i}
+  tags = {{
+    Name = "web-${{var.env}}-{i}"
+  }}
+
+  ebs_block_device {{
+    device_name = "/dev/sdb"
+    volume_size = 100
+  }}
+}}
+"#
+        )
+        .unwrap();
+        i += 1;
+    }
+
+    input
+}
+
+criterion_group!(benches, ser, de, de_large);
 criterion_main!(benches);
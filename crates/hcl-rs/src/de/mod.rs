@@ -30,6 +30,20 @@ impl Deserializer {
         let body = parser::parse(input)?;
         Ok(Deserializer { body })
     }
+
+    /// Creates a new `Deserializer` for the given HCL input using the provided
+    /// [`ParseOptions`][crate::ParseOptions].
+    ///
+    /// # Errors
+    ///
+    /// An [`Error`][Error] is returned when the input is not valid HCL or violates any of the
+    /// limits configured in `options`.
+    ///
+    /// [Error]: ../error/enum.Error.html
+    pub fn from_str_with_options(input: &str, options: &parser::ParseOptions) -> Result<Self> {
+        let body = parser::parse_with_options(input, options)?;
+        Ok(Deserializer { body })
+    }
 }
 
 /// Deserialize an instance of type `T` from a string of HCL text.
@@ -39,9 +53,21 @@ impl Deserializer {
 /// If preserving HCL semantics is required consider deserializing into a [`Body`][Body] instead or
 /// use [`hcl::parse`][parse] to directly parse the input into a [`Body`][Body].
 ///
+/// Expressions are not evaluated during deserialization. When deserializing into a type that
+/// does not preserve HCL expressions (like [`hcl::Value`][Value] or `serde_json::Value`), any
+/// expression that is not a literal value (e.g. the traversal `data.aws_ami.ubuntu.id`) is
+/// deserialized as string containing the expression's source wrapped in an interpolation
+/// sequence (e.g. `"${data.aws_ami.ubuntu.id}"`). Template expressions are deserialized as their
+/// template string.
+///
+/// The input is parsed into an owned [`Body`][Body] before deserialization starts, so string
+/// values can not be borrowed from `s`. Fields of type `&str` are therefore not supported. Use
+/// `String` or `Cow<'de, str>` instead, the latter falls back to an owned string.
+///
 /// [hcl-json-spec]: https://github.com/hashicorp/hcl/blob/main/json/spec.md
 /// [parse]: ../fn.parse.html
 /// [Body]: ../struct.Body.html
+/// [Value]: ../value/enum.Value.html
 ///
 /// # Example
 ///
@@ -94,6 +120,11 @@ where
 ///
 /// See the documentation of [`from_str`][from_str] for more information.
 ///
+/// The parser requires the complete input up front, so the reader is consumed until EOF before
+/// parsing starts. The data is read directly into a single `String` without an intermediate byte
+/// buffer, and since the reader is drained with large reads there is no need to wrap it in a
+/// [`BufReader`][std::io::BufReader] first.
+///
 /// # Example
 ///
 /// ```
@@ -208,6 +239,82 @@ where
     T::deserialize(Deserializer { body })
 }
 
+/// Deserialize a sequence of HCL documents from a string.
+///
+/// Since the top-level structures of two concatenated HCL bodies form a single valid body, the
+/// documents must be separated explicitly: every line which only consists of `---` (optionally
+/// followed by whitespace) ends the current document. Documents that only contain whitespace are
+/// skipped, so leading and trailing separators are allowed.
+///
+/// Separator lines are only recognized between structures, so a `---` line within a heredoc
+/// does not end the document.
+///
+/// The returned iterator yields one result per document. A document which fails to parse or
+/// deserialize produces an error, but does not prevent the following documents from being
+/// deserialized. Line numbers in parser errors are relative to the start of the document.
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use hcl::Value;
+///
+/// let input = r#"
+/// version = 1
+/// ---
+/// version = 2
+/// "#;
+///
+/// let values = hcl::de::from_str_many::<Value>(input).collect::<Result<Vec<_>, _>>()?;
+///
+/// assert_eq!(values, [hcl::value!({ version = 1 }), hcl::value!({ version = 2 })]);
+/// #   Ok(())
+/// # }
+/// ```
+pub fn from_str_many<'de, T>(s: &'de str) -> StreamDeserializer<'de, T>
+where
+    T: de::Deserialize<'de>,
+{
+    StreamDeserializer {
+        rest: Some(s),
+        marker: PhantomData,
+    }
+}
+
+/// An iterator that deserializes a sequence of `---`-separated HCL documents.
+///
+/// This `struct` is created by the [`from_str_many`] function. See its documentation for more.
+pub struct StreamDeserializer<'de, T> {
+    rest: Option<&'de str>,
+    marker: PhantomData<T>,
+}
+
+impl<T> fmt::Debug for StreamDeserializer<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StreamDeserializer")
+            .field("rest", &self.rest)
+            .finish()
+    }
+}
+
+impl<'de, T> Iterator for StreamDeserializer<'de, T>
+where
+    T: de::Deserialize<'de>,
+{
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (document, rest) = parser::split_document(self.rest?);
+            self.rest = rest;
+
+            if !document.trim().is_empty() {
+                return Some(from_str(document));
+            }
+        }
+    }
+}
+
 impl<'de> de::Deserializer<'de> for Deserializer {
     type Error = Error;
 
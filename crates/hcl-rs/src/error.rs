@@ -1,7 +1,10 @@
 //! The `Error` and `Result` types used by this crate.
 use crate::eval;
 use crate::parser::Rule;
-use pest::{error::LineColLocation, Span};
+use pest::{
+    error::{InputLocation, LineColLocation},
+    Span,
+};
 use serde::{de, ser};
 use std::fmt::{self, Display};
 use std::io;
@@ -51,6 +54,19 @@ impl Error {
     }
 
     /// Returns the `Location` in the input where the error happened, if available.
+    ///
+    /// This is available for syntax errors and for errors caused by violating
+    /// [`ParseOptions`][crate::ParseOptions] limits.
+    ///
+    /// ```
+    /// let input = "a = 1\nb = = 2\n";
+    ///
+    /// let err = hcl::parse(input).unwrap_err();
+    /// let location = err.location().unwrap();
+    ///
+    /// assert_eq!((location.line, location.col, location.offset), (2, 5, 10));
+    /// assert_eq!(&input[location.offset..], "= 2\n");
+    /// ```
     pub fn location(&self) -> Option<&Location> {
         match self {
             Error::Message { location, .. } => location.as_ref(),
@@ -99,9 +115,13 @@ impl From<pest::error::Error<Rule>> for Error {
             LineColLocation::Pos((l, c)) | LineColLocation::Span((l, c), (_, _)) => (l, c),
         };
 
+        let offset = match err.location {
+            InputLocation::Pos(offset) | InputLocation::Span((offset, _)) => offset,
+        };
+
         Error::Message {
             msg: err.to_string(),
-            location: Some(Location { line, col }),
+            location: Some(Location { line, col, offset }),
         }
     }
 }
@@ -126,18 +146,30 @@ impl de::Error for Error {
     }
 }
 
-/// One-based line and column at which the error was detected.
+/// One-based line and column, and zero-based byte offset at which the error was detected.
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub struct Location {
     /// The one-based line number of the error.
     pub line: usize,
     /// The one-based column number of the error.
     pub col: usize,
+    /// The zero-based byte offset of the error in the input.
+    ///
+    /// If the input was parsed with
+    /// [`ParseOptions::normalize_newlines`][crate::ParseOptions::normalize_newlines] enabled, the
+    /// offset refers to the input after replacing `\r\n` with `\n` and may be smaller than the
+    /// offset in the original input.
+    pub offset: usize,
 }
 
 impl From<Span<'_>> for Location {
     fn from(span: Span<'_>) -> Self {
         let (line, col) = span.start_pos().line_col();
-        Location { line, col }
+        Location {
+            line,
+            col,
+            offset: span.start(),
+        }
     }
 }
@@ -36,6 +36,8 @@ impl Evaluate for Attribute {
         Ok(Attribute {
             key: self.key.clone(),
             expr: self.expr.evaluate(ctx).map(Into::into)?,
+            comment: self.comment.clone(),
+            span: None,
         })
     }
 }
@@ -50,6 +52,8 @@ impl Evaluate for Block {
             identifier: self.identifier.clone(),
             labels: self.labels.clone(),
             body: self.body.evaluate(ctx)?,
+            comment: self.comment.clone(),
+            span: None,
         })
     }
 }
@@ -268,8 +272,26 @@ impl Evaluate for BinaryOp {
             (Number(lhs), Plus, Number(rhs)) => Number(lhs + rhs),
             (Number(lhs), Minus, Number(rhs)) => Number(lhs - rhs),
             (Number(lhs), Mul, Number(rhs)) => Number(lhs * rhs),
-            (Number(lhs), Div, Number(rhs)) => Number(lhs / rhs),
-            (Number(lhs), Mod, Number(rhs)) => Number(lhs % rhs),
+            // Division and remainder fail on a zero divisor instead of producing a non-finite
+            // number or panicking.
+            (Number(lhs), operator @ (Div | Mod), Number(rhs)) => {
+                let result = if operator == Div {
+                    lhs.checked_div(rhs)
+                } else {
+                    lhs.checked_rem(rhs)
+                };
+
+                match result {
+                    Some(n) => Number(n),
+                    None => {
+                        return Err(ctx.error(ErrorKind::BinaryOp(
+                            Number(lhs),
+                            operator,
+                            Number(rhs),
+                        )))
+                    }
+                }
+            }
             (lhs, operator, rhs) => return Err(ctx.error(ErrorKind::BinaryOp(lhs, operator, rhs))),
         };
 
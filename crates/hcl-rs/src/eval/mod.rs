@@ -282,6 +282,50 @@ impl<'a> Context<'a> {
         Context::default()
     }
 
+    /// Creates a `Context` with a variable declared for each top-level key of a
+    /// [`Value::Object`].
+    ///
+    /// This is useful for evaluating expressions against a variables file, e.g. a parsed
+    /// `.tfvars` file.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use hcl::eval::{Context, Evaluate};
+    /// use hcl::expr::TemplateExpr;
+    /// use hcl::Value;
+    ///
+    /// let vars: Value = hcl::from_str(r#"region = "eu-central-1""#)?;
+    /// let ctx = Context::from_value(vars)?;
+    ///
+    /// let expr = TemplateExpr::from("${region}a");
+    ///
+    /// assert_eq!(expr.evaluate(&ctx)?, Value::from("eu-central-1a"));
+    /// #   Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `value` is not a [`Value::Object`].
+    pub fn from_value(value: Value) -> EvalResult<Self> {
+        match value {
+            Value::Object(object) => {
+                let mut ctx = Context::new();
+
+                for (name, value) in object {
+                    ctx.declare_var(name, value);
+                }
+
+                Ok(ctx)
+            }
+            other => Err(Error::unexpected(other, "an object")),
+        }
+    }
+
     // Create a new child `Context` which has the current one as parent.
     fn child(&self) -> Context<'_> {
         let mut ctx = Context::new();
@@ -419,6 +419,9 @@ impl<'de> de::Deserializer<'de> for Expression {
         V: de::Visitor<'de>,
     {
         match self {
+            #[cfg(feature = "base64")]
+            Expression::String(v) => visitor.visit_byte_buf(crate::util::decode_base64(&v)?),
+            #[cfg(not(feature = "base64"))]
             Expression::String(v) => visitor.visit_string(v),
             Expression::Array(v) => visitor.visit_seq(v.into_deserializer()),
             _ => Err(self.invalid_type(&visitor)),
@@ -23,9 +23,11 @@ pub use self::{
     traversal::{Traversal, TraversalBuilder, TraversalOperator},
     variable::Variable,
 };
+use crate::eval::{Context, Evaluate};
 use crate::format;
 use crate::ser::with_internal_serialization;
-use crate::{Identifier, Number, Result, Value};
+use crate::template::{Element, Template};
+use crate::{Error, Identifier, Number, Result, Value};
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 use std::fmt::{self, Display, Write};
@@ -50,6 +52,9 @@ pub enum Expression {
     /// Represents array.
     Array(Vec<Expression>),
     /// Represents an object.
+    ///
+    /// In HCL source, object items may use either `=` or `:` to separate keys from values. Both
+    /// forms are parsed into the same `Object` and are always formatted using `=`.
     Object(Object<ObjectKey, Expression>),
     /// A quoted string or heredoc that embeds a program written in the template sub-language.
     TemplateExpr(Box<TemplateExpr>),
@@ -81,6 +86,147 @@ impl Expression {
     {
         with_internal_serialization(|| value.serialize(ExpressionSerializer))
     }
+
+    /// If the `Expression` is an Array, returns the associated vector. Returns None otherwise.
+    pub fn as_array(&self) -> Option<&Vec<Expression>> {
+        match self {
+            Expression::Array(array) => Some(array),
+            _ => None,
+        }
+    }
+
+    /// If the `Expression` is a Boolean, represent it as bool. Returns None otherwise.
+    pub fn as_bool(&self) -> Option<bool> {
+        match *self {
+            Expression::Bool(b) => Some(b),
+            _ => None,
+        }
+    }
+
+    /// If the `Expression` is a Number, returns the associated Number. Returns None otherwise.
+    pub fn as_number(&self) -> Option<&Number> {
+        match self {
+            Expression::Number(num) => Some(num),
+            _ => None,
+        }
+    }
+
+    /// If the `Expression` is an Object, returns the associated Object. Returns None otherwise.
+    pub fn as_object(&self) -> Option<&Object<ObjectKey, Expression>> {
+        match self {
+            Expression::Object(object) => Some(object),
+            _ => None,
+        }
+    }
+
+    /// If the `Expression` is a String, returns the associated str. Returns None otherwise.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Expression::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Converts a fully literal `Expression` into a [`Value`].
+    ///
+    /// Literal expressions are null, booleans, numbers, strings, template expressions without
+    /// interpolations or directives, parenthesized literals, and arrays and objects containing
+    /// only literals. Object keys must be identifiers or literal strings, numbers or booleans.
+    ///
+    /// This differs from the infallible `From<Expression>` conversion for [`Value`], which
+    /// converts non-literal expressions into interpolated strings like `"${var.foo}"`. To convert
+    /// a `Value` back into a literal `Expression` use `Expression::from(value)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hcl::{Expression, Value};
+    /// use hcl::expr::Variable;
+    ///
+    /// let expr = Expression::from_iter([("foo", Expression::from(1))]);
+    /// assert_eq!(expr.try_into_value()?, hcl::value!({ foo = 1 }));
+    ///
+    /// let expr = Expression::from_iter([Expression::from(Variable::unchecked("var"))]);
+    /// assert!(expr.try_into_value().is_err());
+    /// # Ok::<(), hcl::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the first non-literal sub-expression that is encountered.
+    pub fn try_into_value(self) -> Result<Value> {
+        match self {
+            Expression::Null => Ok(Value::Null),
+            Expression::Bool(b) => Ok(Value::Bool(b)),
+            Expression::Number(n) => Ok(Value::Number(n)),
+            Expression::String(s) => Ok(Value::String(s)),
+            Expression::Array(array) => array.into_iter().map(Expression::try_into_value).collect(),
+            Expression::Object(object) => object
+                .into_iter()
+                .map(|(key, expr)| Ok((key.try_into_literal_key()?, expr.try_into_value()?)))
+                .collect(),
+            Expression::Parenthesis(expr) => expr.try_into_value(),
+            Expression::TemplateExpr(expr) => {
+                let literal = Template::from_expr(&expr)?
+                    .elements()
+                    .iter()
+                    .map(|element| match element {
+                        Element::Literal(literal) => Some(literal.as_str()),
+                        _ => None,
+                    })
+                    .collect::<Option<String>>();
+
+                literal
+                    .map(Value::String)
+                    .ok_or_else(|| not_a_literal(&Expression::TemplateExpr(expr)))
+            }
+            other => Err(not_a_literal(&other)),
+        }
+    }
+
+    /// Evaluates an `Expression` which does not reference any variables or functions into a
+    /// [`Value`].
+    ///
+    /// This folds constant sub-expressions like arithmetic and logical operations, comparisons,
+    /// conditionals, templates (which is how strings are concatenated in HCL) and `for`
+    /// expressions over literal collections. It is equivalent to evaluating the expression with an
+    /// empty [`Context`][crate::eval::Context] and can be used as a lightweight constant-folding
+    /// pass. Use [`Evaluate`][crate::eval::Evaluate] to evaluate expressions with variables and
+    /// functions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hcl::Value;
+    ///
+    /// let body = hcl::parse(r#"
+    ///     sum = 1 + 2 * 3
+    ///     name = "${"foo"}-${1 < 2 && !false}"
+    ///     count = var.count + 1
+    /// "#)?;
+    ///
+    /// let expr = |key| body.get_attribute(key).unwrap().expr();
+    ///
+    /// assert_eq!(expr("sum").evaluate_const()?, Value::from(7));
+    /// assert_eq!(expr("name").evaluate_const()?, Value::from("foo-true"));
+    /// assert!(expr("count").evaluate_const().is_err());
+    ///
+    /// let div = hcl::parse("div = 1 / 0")?;
+    /// assert!(div.get_attribute("div").unwrap().expr().evaluate_const().is_err());
+    /// # Ok::<(), hcl::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the expression references a variable or calls a function, or if its
+    /// evaluation fails, e.g. because of mismatching operand types or a division by zero.
+    pub fn evaluate_const(&self) -> Result<Value> {
+        self.evaluate(&Context::new()).map_err(Into::into)
+    }
+}
+
+fn not_a_literal(expr: &Expression) -> Error {
+    Error::new(format!("expression `{expr}` is not a literal value"))
 }
 
 impl From<Expression> for Value {
@@ -130,7 +276,7 @@ impl_from_integer!(u8, u16, u32, u64, usize);
 
 impl From<f32> for Expression {
     fn from(f: f32) -> Self {
-        From::from(f as f64)
+        Number::from_f32(f).map_or(Expression::Null, Expression::Number)
     }
 }
 
@@ -286,6 +432,10 @@ impl Display for Expression {
 }
 
 /// Represents an object key.
+///
+/// The parser retains the original style of object keys: bare identifiers are parsed as
+/// `ObjectKey::Identifier`, while quoted keys are parsed as an `ObjectKey::Expression` wrapping an
+/// [`Expression::String`]. When formatted, each key is emitted in its original style.
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum ObjectKey {
@@ -304,6 +454,18 @@ impl ObjectKey {
     {
         ObjectKey::Identifier(identifier.into())
     }
+
+    fn try_into_literal_key(self) -> Result<String> {
+        match self {
+            ObjectKey::Identifier(ident) => Ok(ident.into_inner()),
+            ObjectKey::Expression(expr) => match expr.try_into_value()? {
+                Value::String(s) => Ok(s),
+                Value::Number(n) => Ok(n.to_string()),
+                Value::Bool(b) => Ok(b.to_string()),
+                other => Err(not_a_literal(&Expression::from(other))),
+            },
+        }
+    }
 }
 
 impl<T> From<T> for ObjectKey
@@ -353,6 +515,23 @@ impl Display for ObjectKey {
 ///
 /// *Please note*: raw expressions are not validated during serialization, so it is your
 /// responsiblity to ensure that they are valid HCL.
+///
+/// Raw expressions are written verbatim, without quoting:
+///
+/// ```
+/// use hcl::{Attribute, Body, RawExpression};
+///
+/// let body = Body::from(Attribute::new("count", RawExpression::new("var.instances")));
+///
+/// assert_eq!(hcl::format::to_string(&body)?, "count = var.instances\n");
+/// # Ok::<(), hcl::Error>(())
+/// ```
+///
+/// Raw expressions are write-only: the parser never produces them unless
+/// [`ParseOptions::raw_expressions`][crate::ParseOptions::raw_expressions] is enabled. Parsing
+/// the output above thus yields a [`Traversal`] instead of a `RawExpression`, so a `Body`
+/// containing raw expressions does not compare equal to the result of parsing its formatted
+/// output.
 #[derive(Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
 #[serde(transparent)]
 pub struct RawExpression(String);
@@ -368,4 +368,39 @@ mod tests {
 
         assert_normalizes_to!(op.clone(), op);
     }
+
+    #[test]
+    fn operator_symbols() {
+        let unary = [("-", UnaryOperator::Neg), ("!", UnaryOperator::Not)];
+
+        for (symbol, operator) in unary {
+            assert_eq!(symbol.parse::<UnaryOperator>().unwrap(), operator);
+            assert_eq!(operator.to_string(), symbol);
+        }
+
+        let binary = [
+            ("==", BinaryOperator::Eq),
+            ("!=", BinaryOperator::NotEq),
+            ("<=", BinaryOperator::LessEq),
+            (">=", BinaryOperator::GreaterEq),
+            ("<", BinaryOperator::Less),
+            (">", BinaryOperator::Greater),
+            ("+", BinaryOperator::Plus),
+            ("-", BinaryOperator::Minus),
+            ("*", BinaryOperator::Mul),
+            ("/", BinaryOperator::Div),
+            ("%", BinaryOperator::Mod),
+            ("&&", BinaryOperator::And),
+            ("||", BinaryOperator::Or),
+        ];
+
+        for (symbol, operator) in binary {
+            assert_eq!(symbol.parse::<BinaryOperator>().unwrap(), operator);
+            assert_eq!(operator.to_string(), symbol);
+        }
+
+        assert!("+".parse::<UnaryOperator>().is_err());
+        assert!("!".parse::<BinaryOperator>().is_err());
+        assert!("and".parse::<BinaryOperator>().is_err());
+    }
 }
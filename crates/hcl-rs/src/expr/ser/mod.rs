@@ -141,7 +141,7 @@ impl ser::Serializer for ExpressionSerializer {
     }
 
     fn serialize_f32(self, value: f32) -> Result<Self::Ok> {
-        self.serialize_f64(value as f64)
+        Ok(Number::from_f32(value).map_or(Expression::Null, Expression::Number))
     }
 
     fn serialize_f64(self, value: f64) -> Result<Self::Ok> {
@@ -156,6 +156,12 @@ impl ser::Serializer for ExpressionSerializer {
         Ok(Expression::String(value.to_owned()))
     }
 
+    #[cfg(feature = "base64")]
+    fn serialize_bytes(self, value: &[u8]) -> Result<Self::Ok> {
+        Ok(Expression::String(crate::util::encode_base64(value)))
+    }
+
+    #[cfg(not(feature = "base64"))]
     fn serialize_bytes(self, value: &[u8]) -> Result<Self::Ok> {
         let vec = value
             .iter()
@@ -439,12 +445,12 @@ impl ser::Serializer for ObjectKeySerializer {
     type SerializeTupleStruct = Impossible<ObjectKey, Error>;
     type SerializeTupleVariant = Impossible<ObjectKey, Error>;
     type SerializeMap = Impossible<ObjectKey, Error>;
-    type SerializeStruct = Impossible<ObjectKey, Error>;
+    type SerializeStruct = SerializeObjectKeyStruct;
     type SerializeStructVariant = Impossible<ObjectKey, Error>;
 
     serialize_unsupported! {
         bool f32 f64 bytes unit unit_struct none
-        seq tuple tuple_struct tuple_variant map struct struct_variant
+        seq tuple tuple_struct tuple_variant map struct_variant
     }
 
     serialize_self! { some newtype_struct newtype_variant }
@@ -497,4 +503,38 @@ impl ser::Serializer for ObjectKeySerializer {
     ) -> Result<Self::Ok> {
         Identifier::new(variant).map(ObjectKey::Identifier)
     }
+
+    fn serialize_struct(self, name: &'static str, _len: usize) -> Result<Self::SerializeStruct> {
+        // Only expression keys like `(var.key)`, which are passed via internal handles, are
+        // supported here.
+        if name == EXPR_HANDLE_MARKER {
+            Ok(SerializeObjectKeyStruct {
+                inner: SerializeInternalHandleStruct::new(),
+            })
+        } else {
+            Err(ser::Error::custom("`serialize_struct` not supported"))
+        }
+    }
+}
+
+pub(crate) struct SerializeObjectKeyStruct {
+    inner: SerializeInternalHandleStruct,
+}
+
+impl ser::SerializeStruct for SerializeObjectKeyStruct {
+    type Ok = ObjectKey;
+    type Error = Error;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<()>
+    where
+        T: ?Sized + ser::Serialize,
+    {
+        self.inner.serialize_field(key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok> {
+        self.inner
+            .end()
+            .map(|handle| ObjectKey::Expression(EXPR_HANDLES.with(|eh| eh.remove(handle))))
+    }
 }
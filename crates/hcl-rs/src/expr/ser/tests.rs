@@ -31,6 +31,8 @@ fn roundtrip() {
 #[test]
 fn builtin() {
     assert_expr(Some(1u8), Expression::Number(1u8.into()));
+    assert_expr(0.1f32, Expression::Number(Number::from_f64(0.1).unwrap()));
+    assert_expr(f32::NAN, Expression::Null);
 
     assert_expr(
         Conditional::new(Variable::unchecked("some_cond_var"), "yes", "no"),
@@ -1,5 +1,7 @@
+use crate::eval::{Context, Evaluate};
+use crate::template::Template;
 use crate::util::try_unescape;
-use crate::{Error, Identifier, Result};
+use crate::{Error, Identifier, Map, Result, Value};
 use serde::Deserialize;
 use std::fmt;
 use std::str::FromStr;
@@ -29,6 +31,48 @@ impl TemplateExpr {
             TemplateExpr::Heredoc(heredoc) => &heredoc.template,
         }
     }
+
+    /// Renders the template into a `String` using `vars` as variables.
+    ///
+    /// This is a shorthand for evaluating the template with a [`Context`] which has a variable
+    /// declared for each entry of `vars`. Apart from simple `${name}` interpolations, this
+    /// supports arbitrary expressions in interpolations (e.g. `${tags.env}`) as well as `if` and
+    /// `for` directives. Interpolated numbers and booleans are converted to strings. Use
+    /// [`Evaluate`] with a custom `Context` if functions are needed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hcl::expr::TemplateExpr;
+    /// use hcl::{Map, Value};
+    ///
+    /// let vars = Map::from([
+    ///     ("name".to_owned(), Value::from("web")),
+    ///     ("env".to_owned(), Value::from("prod")),
+    /// ]);
+    ///
+    /// let expr = TemplateExpr::from("${name}-${env}");
+    /// assert_eq!(expr.render(&vars)?, "web-prod");
+    ///
+    /// let expr = TemplateExpr::from("${name}-${region}");
+    /// assert!(expr.render(&vars).is_err());
+    /// # Ok::<(), hcl::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the template cannot be parsed, references an unknown variable, calls a
+    /// function or if evaluating one of its expressions fails.
+    pub fn render(&self, vars: &Map<String, Value>) -> Result<String> {
+        let mut ctx = Context::new();
+
+        for (name, value) in vars {
+            ctx.declare_var(name.as_str(), value.clone());
+        }
+
+        let template = Template::from_expr(self)?;
+        Ok(template.evaluate(&ctx)?)
+    }
 }
 
 impl From<&str> for TemplateExpr {
@@ -96,6 +140,9 @@ pub enum HeredocStripMode {
     /// of leading spaces, and then that number of prefix spaces is removed from all line-leading
     /// literal strings. The final closing marker may also have an arbitrary number of spaces
     /// preceding it on its line.
+    ///
+    /// When formatted, the template lines are re-indented one level deeper than the closing
+    /// marker, which is aligned with the surrounding structure.
     Indent,
 }
 
@@ -57,6 +57,32 @@ impl Traversal {
 /// #     Ok(())
 /// # }
 /// ```
+///
+/// Splat operators are supported as well. Since the attribute-only splat (`.*`) and the full
+/// splat (`[*]`) behave differently, each is serialized in its own form:
+///
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use hcl::expr::{Expression, Traversal, Variable};
+///
+/// let full_splat = Traversal::builder(Variable::new("var")?)
+///     .attr("list")
+///     .full_splat()
+///     .attr("id")
+///     .build();
+///
+/// assert_eq!(Expression::from(full_splat).to_string(), "var.list[*].id");
+///
+/// let attr_splat = Traversal::builder(Variable::new("var")?)
+///     .attr("list")
+///     .attr_splat()
+///     .attr("id")
+///     .build();
+///
+/// assert_eq!(Expression::from(attr_splat).to_string(), "var.list.*.id");
+/// #     Ok(())
+/// # }
+/// ```
 #[derive(Debug)]
 pub struct TraversalBuilder {
     expr: Expression,
@@ -107,11 +133,15 @@ impl TraversalBuilder {
 /// The expression traversal operators that are supported by HCL.
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 pub enum TraversalOperator {
-    /// The attribute-only splat operator supports only attribute lookups into the elements from a
-    /// list, but supports an arbitrary number of them.
+    /// The attribute-only splat operator (`.*`) supports only attribute lookups into the elements
+    /// from a list, but supports an arbitrary number of them.
+    ///
+    /// This is the legacy splat syntax (e.g. `list.*.id`). It is kept distinct from
+    /// [`FullSplat`][TraversalOperator::FullSplat] so that formatting reproduces the original
+    /// syntax.
     AttrSplat,
-    /// The full splat operator additionally supports indexing into the elements from a list, and
-    /// allows any combination of attribute access and index operations.
+    /// The full splat operator (`[*]`) additionally supports indexing into the elements from a
+    /// list, and allows any combination of attribute access and index operations.
     FullSplat,
     /// The attribute access operator returns the value of a single attribute in an object value.
     GetAttr(Identifier),
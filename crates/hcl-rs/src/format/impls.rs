@@ -1,4 +1,4 @@
-use super::{private, Format, Formatter};
+use super::{private, Format, Formatter, QuoteStyle};
 use crate::expr::{
     BinaryOp, Conditional, Expression, ForExpr, FuncCall, Heredoc, HeredocStripMode, ObjectKey,
     Operation, RawExpression, TemplateExpr, Traversal, TraversalOperator, UnaryOp, Variable,
@@ -7,8 +7,8 @@ use crate::structure::{Attribute, Block, BlockLabel, Body, Structure};
 use crate::template::{
     Directive, Element, ForDirective, IfDirective, Interpolation, StripMode, Template,
 };
-use crate::util::{is_ident, is_templated};
-use crate::{Identifier, Number, Result, Value};
+use crate::util::{dedent, is_ident, is_templated};
+use crate::{Identifier, Number, Object, Result, Value};
 use std::io;
 
 impl<T> private::Sealed for &T where T: Format {}
@@ -32,14 +32,58 @@ impl Format for Body {
     where
         W: io::Write,
     {
-        for structure in self.iter() {
-            structure.format(fmt)?;
+        if fmt.config.write_bom && !fmt.bom_written {
+            fmt.write_bytes("\u{feff}".as_bytes())?;
+            fmt.bom_written = true;
+        }
+
+        // An empty top-level body produces no output unless a trailing newline is requested.
+        if self.0.is_empty() && fmt.current_indent == 0 && fmt.config.trailing_newline {
+            return fmt.write_bytes(b"\n");
+        }
+
+        if fmt.config.align_attributes {
+            for (structure, width) in self.iter().zip(attribute_key_widths(self, fmt)) {
+                fmt.attribute_key_width = width;
+                structure.format(fmt)?;
+            }
+        } else {
+            for structure in self.iter() {
+                structure.format(fmt)?;
+            }
         }
 
         Ok(())
     }
 }
 
+// Returns the width each structure's key is padded to when attribute alignment is enabled. This is
+// the length of the longest key within each run of consecutive attributes. Blocks and attributes
+// which are formatted as blocks end a run.
+fn attribute_key_widths<W>(body: &Body, fmt: &Formatter<W>) -> Vec<usize> {
+    let mut widths = vec![0; body.len()];
+    let mut run_start = 0;
+    let mut max_width = 0;
+
+    for (i, structure) in body.iter().enumerate() {
+        match structure {
+            Structure::Attribute(attr)
+                if !(fmt.config.object_attrs_as_blocks && block_object(&attr.expr).is_some()) =>
+            {
+                max_width = max_width.max(attr.key.chars().count());
+            }
+            _ => {
+                widths[run_start..i].fill(max_width);
+                run_start = i + 1;
+                max_width = 0;
+            }
+        }
+    }
+
+    widths[run_start..].fill(max_width);
+    widths
+}
+
 impl private::Sealed for Structure {}
 
 impl Format for Structure {
@@ -61,14 +105,70 @@ impl Format for Attribute {
     where
         W: io::Write,
     {
-        fmt.begin_attribute()?;
+        if fmt.config.object_attrs_as_blocks {
+            if let Some(object) = block_object(&self.expr) {
+                let block = Block {
+                    identifier: self.key.clone(),
+                    labels: Vec::new(),
+                    body: object_block_body(object),
+                    comment: self.comment.clone(),
+                    span: None,
+                };
+
+                return format_block(&block, fmt, false);
+            }
+        }
+
+        fmt.begin_attribute(self.comment.as_deref())?;
         self.key.format(fmt)?;
+        fmt.align_attribute_key(&self.key)?;
         fmt.begin_attribute_value()?;
-        self.expr.format(fmt)?;
+
+        match &self.expr {
+            Expression::String(s) if fmt.prefers_heredoc(&self.key, s) => {
+                match string_heredoc(s, fmt.config.escape_template_sequences) {
+                    Some(heredoc) => heredoc.format(fmt)?,
+                    None => s.format(fmt)?,
+                }
+            }
+            expr => expr.format(fmt)?,
+        }
+
         fmt.end_attribute()
     }
 }
 
+// Converts a string into a heredoc if it can be represented as such without altering its value.
+fn string_heredoc(s: &str, escape_templates: bool) -> Option<Heredoc> {
+    if !s.ends_with('\n')
+        || s.chars()
+            .any(|ch| ch.is_control() && ch != '\n' && ch != '\t')
+    {
+        return None;
+    }
+
+    // Escape sequences are only interpreted in quoted templates.
+    if !escape_templates && is_templated(s) && s.contains('\\') {
+        return None;
+    }
+
+    let mut delimiter = String::from("EOT");
+    let mut n = 0;
+
+    while s.lines().any(|line| line.trim() == delimiter) {
+        n += 1;
+        delimiter = format!("EOT{n}");
+    }
+
+    if escape_templates {
+        // Heredocs are templates, so literal template sequence introductions need to be escaped.
+        let template = s.replace("${", "$${").replace("%{", "%%{");
+        Some(Heredoc::new(Identifier::unchecked(delimiter), template))
+    } else {
+        Some(Heredoc::new(Identifier::unchecked(delimiter), s))
+    }
+}
+
 impl private::Sealed for Block {}
 
 impl Format for Block {
@@ -76,20 +176,62 @@ impl Format for Block {
     where
         W: io::Write,
     {
-        fmt.begin_block()?;
-        self.identifier.format(fmt)?;
+        format_block(self, fmt, true)
+    }
+}
 
-        for label in &self.labels {
-            fmt.write_bytes(b" ")?;
-            label.format(fmt)?;
+fn format_block<W>(block: &Block, fmt: &mut Formatter<W>, hooks: bool) -> Result<()>
+where
+    W: io::Write,
+{
+    let hook_block = hooks.then_some(block);
+
+    fmt.begin_block(hook_block, block.comment.as_deref())?;
+    block.identifier.format(fmt)?;
+
+    for label in &block.labels {
+        fmt.write_bytes(b" ")?;
+        label.format(fmt)?;
+    }
+
+    fmt.begin_block_body()?;
+    block.body.format(fmt)?;
+    fmt.end_block(hook_block)
+}
+
+// Returns the object if an attribute with the given value can be formatted as a block, i.e. if it
+// is a non-empty object whose keys are all valid identifiers. Returns `None` otherwise.
+fn block_object(expr: &Expression) -> Option<&Object<ObjectKey, Expression>> {
+    match expr {
+        Expression::Object(object)
+            if !object.is_empty() && object.keys().all(|key| block_key(key).is_some()) =>
+        {
+            Some(object)
         }
+        _ => None,
+    }
+}
 
-        fmt.begin_block_body()?;
-        self.body.format(fmt)?;
-        fmt.end_block()
+// Returns the identifier an object key is converted to when the object is formatted as a block.
+fn block_key(key: &ObjectKey) -> Option<&str> {
+    match key {
+        ObjectKey::Identifier(ident) => Some(ident.as_str()),
+        ObjectKey::Expression(Expression::String(s)) if is_ident(s) => Some(s),
+        ObjectKey::Expression(_) => None,
     }
 }
 
+// Converts an object returned by `block_object` into a block body.
+fn object_block_body(object: &Object<ObjectKey, Expression>) -> Body {
+    object
+        .iter()
+        .filter_map(|(key, expr)| {
+            let key = Identifier::unchecked(block_key(key)?);
+            Some(Attribute::new(key, expr.clone()))
+        })
+        .collect()
+}
+
 impl private::Sealed for BlockLabel {}
 
 impl Format for BlockLabel {
@@ -188,10 +330,10 @@ impl<'a> Format for StrKey<'a> {
     where
         W: io::Write,
     {
-        if fmt.config.prefer_ident_keys && is_ident(self.0) {
+        if fmt.config.quote_object_keys == QuoteStyle::WhenNeeded && is_ident(self.0) {
             fmt.write_string_fragment(self.0)
         } else {
-            fmt.write_quoted_string(self.0, !is_templated(self.0))
+            fmt.write_quoted_string_value(self.0)
         }
     }
 }
@@ -215,7 +357,9 @@ impl Format for TemplateExpr {
         W: io::Write,
     {
         match self {
-            TemplateExpr::QuotedString(string) => string.format(fmt),
+            TemplateExpr::QuotedString(string) => {
+                fmt.write_quoted_string(string, !is_templated(string))
+            }
             TemplateExpr::Heredoc(heredoc) => heredoc.format(fmt),
         }
     }
@@ -231,7 +375,22 @@ impl Format for Heredoc {
         fmt.write_string_fragment(self.strip.as_str())?;
         fmt.write_string_fragment(&self.delimiter)?;
         fmt.write_bytes(b"\n")?;
-        fmt.write_string_fragment(&self.template)?;
+
+        match self.strip {
+            HeredocStripMode::None => fmt.write_string_fragment(&self.template)?,
+            HeredocStripMode::Indent => {
+                // Leading whitespace is stripped from indented heredocs, so the template lines can
+                // be re-indented to align with the surrounding structure without changing the
+                // heredoc's value. Empty lines are not indented to avoid trailing whitespace.
+                for line in dedent(&self.template).split_inclusive('\n') {
+                    if !line.trim_end_matches(['\r', '\n']).is_empty() {
+                        fmt.write_indent(fmt.current_indent + 1)?;
+                    }
+
+                    fmt.write_string_fragment(line)?;
+                }
+            }
+        }
 
         if !self.template.ends_with('\n') {
             fmt.write_bytes(b"\n")?;
@@ -551,7 +710,7 @@ impl Format for String {
     where
         W: io::Write,
     {
-        fmt.write_quoted_string(self, !is_templated(self))
+        fmt.write_quoted_string_value(self)
     }
 }
 
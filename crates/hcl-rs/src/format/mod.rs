@@ -38,7 +38,9 @@ mod escape;
 mod impls;
 
 use self::escape::{CharEscape, ESCAPE};
-use crate::Result;
+use crate::util::is_templated;
+use crate::{Block, Result};
+use std::borrow::Cow;
 use std::io;
 
 mod private {
@@ -69,7 +71,8 @@ pub trait Format: private::Sealed {
     {
         self.format(fmt)?;
         // "Drain" the buffer by splitting off all bytes, leaving the formatter's buffer empty
-        // ready for reuse.
+        // ready for reuse. The next output starts with a BOM again, if enabled.
+        fmt.bom_written = false;
         Ok(fmt.writer.as_mut().split_off(0))
     }
 
@@ -77,13 +80,29 @@ pub trait Format: private::Sealed {
     ///
     /// # Errors
     ///
-    /// Formatting the data structure or writing to the writer may fail with an `Error`.
+    /// Formatting the data structure or writing to the writer may fail with an `Error`. An error
+    /// is also returned if the formatter's indent or assignment separator is not valid UTF-8.
     fn format_string<W>(&self, fmt: &mut Formatter<W>) -> Result<String>
     where
         W: io::Write + AsMut<Vec<u8>>,
     {
+        // The indent and the assignment separator are the only user-provided raw byte sequences
+        // that are written by the formatter, everything else originates from `&str`.
+        std::str::from_utf8(&fmt.config.indent)?;
+        std::str::from_utf8(fmt.config.assignment)?;
         let bytes = self.format_vec(fmt)?;
-        // SAFETY: The `Formatter` never emits invalid UTF-8.
+
+        // Block hooks can write arbitrary bytes, so the output needs to be validated.
+        if fmt.config.before_block.is_some() || fmt.config.after_block.is_some() {
+            return String::from_utf8(bytes).map_err(|err| err.utf8_error().into());
+        }
+
+        debug_assert!(
+            std::str::from_utf8(&bytes).is_ok(),
+            "Formatter emitted invalid UTF-8"
+        );
+        // SAFETY: The `Formatter` never emits invalid UTF-8 and the indent and assignment separator
+        // were validated above.
         Ok(unsafe { String::from_utf8_unchecked(bytes) })
     }
 }
@@ -98,26 +117,61 @@ enum FormatState {
     BlockBodyStart,
 }
 
+/// A hook which is invoked by the formatter for top-level blocks.
+type BlockHook<'a> = Box<dyn FnMut(&Block, &mut dyn io::Write) -> io::Result<()> + 'a>;
+
 struct FormatConfig<'a> {
-    indent: &'a [u8],
+    indent: Cow<'a, [u8]>,
+    assignment: &'a [u8],
     dense: bool,
     compact_arrays: bool,
     compact_objects: bool,
-    prefer_ident_keys: bool,
+    quote_object_keys: QuoteStyle,
+    object_attrs_as_blocks: bool,
+    align_attributes: bool,
+    trailing_newline: bool,
+    max_line_width: Option<usize>,
+    multiline_heredocs: bool,
+    escape_template_sequences: bool,
+    write_bom: bool,
+    before_block: Option<BlockHook<'a>>,
+    after_block: Option<BlockHook<'a>>,
 }
 
 impl<'a> Default for FormatConfig<'a> {
     fn default() -> Self {
         FormatConfig {
-            indent: b"  ",
+            indent: Cow::Borrowed(b"  "),
+            assignment: b" = ",
             dense: false,
             compact_arrays: false,
             compact_objects: false,
-            prefer_ident_keys: false,
+            quote_object_keys: QuoteStyle::Always,
+            object_attrs_as_blocks: false,
+            align_attributes: false,
+            trailing_newline: false,
+            max_line_width: None,
+            multiline_heredocs: false,
+            escape_template_sequences: false,
+            write_bom: false,
+            before_block: None,
+            after_block: None,
         }
     }
 }
 
+/// Controls when the [`Formatter`] quotes strings that could also be written as bare identifiers.
+///
+/// See [`FormatterBuilder::quote_object_keys`] for usage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum QuoteStyle {
+    /// Always quote strings.
+    Always,
+    /// Only quote strings that are not valid HCL identifiers.
+    WhenNeeded,
+}
+
 /// A pretty printing HCL formatter.
 ///
 /// # Examples
@@ -179,6 +233,30 @@ pub struct Formatter<'a, W> {
     current_indent: usize,
     has_value: bool,
     compact_mode_level: u64,
+    bytes_written: usize,
+    attribute_key_width: usize,
+    bom_written: bool,
+}
+
+// A writer wrapper which counts the bytes written to the inner writer.
+struct CountingWriter<'w, W> {
+    inner: &'w mut W,
+    count: &'w mut usize,
+}
+
+impl<W> io::Write for CountingWriter<'_, W>
+where
+    W: io::Write,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        *self.count += n;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
 }
 
 /// A builder to create a `Formatter`.
@@ -192,8 +270,72 @@ impl<'a> FormatterBuilder<'a> {
     /// Set the indent for indenting nested HCL structures.
     ///
     /// The default indentation is two spaces.
+    ///
+    /// The indent must be valid UTF-8, otherwise formatting as `String` fails with an error.
     pub fn indent(mut self, indent: &'a [u8]) -> Self {
-        self.config.indent = indent;
+        self.config.indent = Cow::Borrowed(indent);
+        self
+    }
+
+    /// Indent nested HCL structures with `n` spaces.
+    ///
+    /// This is a convenience alternative to [`indent`][FormatterBuilder::indent] which does not
+    /// require the caller to keep the indent alive.
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use hcl::format::{Format, Formatter};
+    ///
+    /// let body = hcl::body!({ block { foo = "bar" } });
+    ///
+    /// let mut formatter = Formatter::builder().indent_spaces(4).build_vec();
+    ///
+    /// assert_eq!(body.format_string(&mut formatter)?, "block {\n    foo = \"bar\"\n}\n");
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn indent_spaces(mut self, n: usize) -> Self {
+        self.config.indent = Cow::Owned(vec![b' '; n]);
+        self
+    }
+
+    /// Indent nested HCL structures with one tab per level.
+    pub fn indent_tab(mut self) -> Self {
+        self.config.indent = Cow::Borrowed(b"\t");
+        self
+    }
+
+    /// Set the separator written between attribute keys and values, and between object keys and
+    /// values.
+    ///
+    /// The default separator is `" = "`. HCL also accepts `:` between object keys and values, but
+    /// attributes always require `=`, so any other separator produces output that is not valid
+    /// HCL when the formatted value contains attributes. This can be useful to mimic other
+    /// configuration styles.
+    ///
+    /// The separator must be valid UTF-8, otherwise formatting as `String` fails with an error.
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use hcl::format::{Format, Formatter};
+    ///
+    /// let expr = hcl::expression!({ foo = 1 });
+    ///
+    /// let mut formatter = Formatter::builder()
+    ///     .assignment(b": ")
+    ///     .compact_objects(true)
+    ///     .build_vec();
+    ///
+    /// assert_eq!(expr.format_string(&mut formatter)?, "{ foo: 1 }");
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn assignment(mut self, assignment: &'a [u8]) -> Self {
+        self.config.assignment = assignment;
         self
     }
 
@@ -219,6 +361,11 @@ impl<'a> FormatterBuilder<'a> {
     /// block1 {}
     /// block2 {}
     /// ```
+    ///
+    /// Blank lines of the original input are not retained when parsing, so the formatter never
+    /// emits more than one consecutive blank line. When a parsed document is formatted again,
+    /// blank lines between attributes are removed, and any run of blank lines around a block is
+    /// collapsed into a single blank line (or removed in dense mode).
     pub fn dense(mut self, yes: bool) -> Self {
         self.config.dense = yes;
         self
@@ -299,8 +446,310 @@ impl<'a> FormatterBuilder<'a> {
     /// ```
     ///
     /// [ident-variant]: crate::expr::ObjectKey::Identifier
-    pub fn prefer_ident_keys(mut self, yes: bool) -> Self {
-        self.config.prefer_ident_keys = yes;
+    ///
+    /// This is a shorthand for [`quote_object_keys`][FormatterBuilder::quote_object_keys] using
+    /// [`QuoteStyle::WhenNeeded`] if `yes` is `true` and [`QuoteStyle::Always`] otherwise.
+    pub fn prefer_ident_keys(self, yes: bool) -> Self {
+        let style = if yes {
+            QuoteStyle::WhenNeeded
+        } else {
+            QuoteStyle::Always
+        };
+
+        self.quote_object_keys(style)
+    }
+
+    /// Controls the quoting of object keys which are strings.
+    ///
+    /// With [`QuoteStyle::Always`] (the default), string object keys are always quoted. With
+    /// [`QuoteStyle::WhenNeeded`], only string object keys that are not valid HCL identifiers
+    /// are quoted.
+    ///
+    /// Object keys of variant [`ObjectKey::Identifier`][ident-variant] are never quoted, and
+    /// keys of any other expression type are formatted as-is, regardless of this setting.
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use hcl::format::{Format, Formatter, QuoteStyle};
+    ///
+    /// let body = hcl::body!({ object = { "foo" = 1, "foo-bar" = 2, "123" = 3 } });
+    ///
+    /// let mut formatter = Formatter::builder()
+    ///     .quote_object_keys(QuoteStyle::WhenNeeded)
+    ///     .compact_objects(true)
+    ///     .build_vec();
+    ///
+    /// assert_eq!(
+    ///     body.format_string(&mut formatter)?,
+    ///     "object = { foo = 1, foo-bar = 2, \"123\" = 3 }\n"
+    /// );
+    /// #   Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [ident-variant]: crate::expr::ObjectKey::Identifier
+    pub fn quote_object_keys(mut self, style: QuoteStyle) -> Self {
+        self.config.quote_object_keys = style;
+        self
+    }
+
+    /// Controls whether attributes with object values are formatted like blocks.
+    ///
+    /// By default, object-valued attributes are formatted with an `=`:
+    ///
+    /// ```hcl
+    /// tags = {
+    ///   env = "prod"
+    /// }
+    /// ```
+    ///
+    /// When enabled, they are formatted in block style instead, while attributes with any other
+    /// value, including arrays, are not affected:
+    ///
+    /// ```hcl
+    /// tags {
+    ///   env = "prod"
+    /// }
+    /// ```
+    ///
+    /// This also applies to objects nested within such attributes. Empty objects and objects with
+    /// keys that are not valid identifiers are always formatted as attributes since they cannot
+    /// be represented in block style. Block hooks are not invoked for attributes formatted in
+    /// block style.
+    ///
+    /// Note that HCL parses the output as blocks and not as attributes, so it will not parse
+    /// back into an equivalent [`Body`][crate::Body]. The default is `false`.
+    pub fn object_attrs_as_blocks(mut self, yes: bool) -> Self {
+        self.config.object_attrs_as_blocks = yes;
+        self
+    }
+
+    /// If set, the keys of consecutive attributes within a body are padded to the width of the
+    /// longest key, so that their assignments line up like with `terraform fmt`.
+    ///
+    /// A block ends a run of consecutive attributes, and the attributes of each block body are
+    /// aligned independently. Object items are not aligned. Disabled by default.
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use hcl::format::{Format, Formatter};
+    ///
+    /// let body = hcl::body!({
+    ///     ami = "ami-123"
+    ///     instance_type = "t3.micro"
+    /// });
+    ///
+    /// let mut formatter = Formatter::builder().align_attributes(true).build_vec();
+    ///
+    /// let expected = r#"
+    /// ami           = "ami-123"
+    /// instance_type = "t3.micro"
+    /// "#.trim_start();
+    ///
+    /// assert_eq!(body.format_string(&mut formatter)?, expected);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn align_attributes(mut self, yes: bool) -> Self {
+        self.config.align_attributes = yes;
+        self
+    }
+
+    /// Controls the output for empty bodies.
+    ///
+    /// Every attribute and block is terminated by a newline, so formatting a non-empty
+    /// [`Body`][crate::Body] always produces output which ends with a newline. An empty `Body`
+    /// does not contain any structures and is formatted as an empty string by default.
+    ///
+    /// When trailing newlines are enabled, an empty top-level `Body` is formatted as a single
+    /// newline instead. Empty block bodies (e.g. `block {}`) are not affected by this option.
+    pub fn trailing_newline(mut self, yes: bool) -> Self {
+        self.config.trailing_newline = yes;
+        self
+    }
+
+    /// Sets the maximum line width for string attributes.
+    ///
+    /// If set, string attribute values which would exceed the line width when formatted as a
+    /// quoted string are formatted as heredoc instead:
+    ///
+    /// ```hcl
+    /// script = <<EOT
+    /// #!/bin/sh
+    /// echo "hello world"
+    /// EOT
+    /// ```
+    ///
+    /// A heredoc template always ends with a newline and does not support escape sequences.
+    /// Strings which do not end with a newline or contain control characters other than newlines
+    /// and tabs cannot be represented losslessly as heredoc and are always formatted as quoted
+    /// strings, even if they exceed the maximum line width.
+    ///
+    /// By default, the line width is not limited.
+    pub fn max_line_width(mut self, width: Option<usize>) -> Self {
+        self.config.max_line_width = width;
+        self
+    }
+
+    /// Controls whether multi-line string attribute values are formatted as heredoc.
+    ///
+    /// When enabled, string attribute values containing newlines are formatted as heredoc
+    /// instead of a quoted string with `\n` escape sequences, regardless of their length. This
+    /// makes embedded scripts or policy documents readable. The same restrictions as for
+    /// [`max_line_width`][FormatterBuilder::max_line_width] apply: strings which cannot be
+    /// represented losslessly as heredoc are still formatted as quoted strings. Strings nested
+    /// in arrays or objects are not affected. The default is `false`.
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use hcl::format::{Format, Formatter};
+    /// use hcl::Body;
+    ///
+    /// let body = Body::builder()
+    ///     .add_attribute(("user_data", "#!/bin/sh\necho hello\n"))
+    ///     .build();
+    ///
+    /// let mut formatter = Formatter::builder()
+    ///     .multiline_heredocs(true)
+    ///     .build_vec();
+    ///
+    /// assert_eq!(
+    ///     body.format_string(&mut formatter)?,
+    ///     "user_data = <<EOT\n#!/bin/sh\necho hello\nEOT\n"
+    /// );
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn multiline_heredocs(mut self, yes: bool) -> Self {
+        self.config.multiline_heredocs = yes;
+        self
+    }
+
+    /// Controls whether template sequence introductions (`${` and `%{`) in strings are escaped.
+    ///
+    /// By default, strings which contain template sequences are written verbatim, so that they
+    /// are interpreted as templates when the output is parsed again. This allows producing
+    /// interpolations like `"${var.region}"` from plain strings, e.g. when serializing a
+    /// [`Value`][crate::Value].
+    ///
+    /// If enabled, strings are treated as literal values instead and template sequence
+    /// introductions are escaped as `$${` and `%%{`. This applies to strings, object keys, block
+    /// labels and strings that are formatted as heredocs. Template expressions are not affected.
+    /// Use [`ParseOptions::unescape_template_sequences`][unescape] to decode the escaped sequences
+    /// again when parsing. The default is `false`.
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use hcl::format::{Format, Formatter};
+    /// use hcl::Body;
+    ///
+    /// let body = Body::builder().add_attribute(("literal", "${x}")).build();
+    ///
+    /// let mut formatter = Formatter::builder()
+    ///     .escape_template_sequences(true)
+    ///     .build_vec();
+    ///
+    /// assert_eq!(body.format_string(&mut formatter)?, "literal = \"$${x}\"\n");
+    /// #   Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [unescape]: crate::ParseOptions::unescape_template_sequences
+    pub fn escape_template_sequences(mut self, yes: bool) -> Self {
+        self.config.escape_template_sequences = yes;
+        self
+    }
+
+    /// Controls whether a UTF-8 byte order mark (BOM) is written at the start of the output.
+    ///
+    /// Some Windows tools require UTF-8 encoded files to start with a BOM. When enabled, the
+    /// bytes `EF BB BF` are written once before the first formatted [`Body`][crate::Body], even
+    /// if multiple bodies are written to the same writer. Each output returned by
+    /// [`Format::format_vec`] or [`Format::format_string`] starts with a BOM. This also applies
+    /// to data serialized with a [`Serializer`][crate::ser::Serializer] using this formatter. The
+    /// default is `false`.
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use hcl::format::{Format, Formatter};
+    ///
+    /// let mut formatter = Formatter::builder().write_bom(true).build_vec();
+    ///
+    /// let body = hcl::body!({ foo = "bar" });
+    ///
+    /// assert_eq!(body.format_vec(&mut formatter)?, b"\xEF\xBB\xBFfoo = \"bar\"\n");
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn write_bom(mut self, yes: bool) -> Self {
+        self.config.write_bom = yes;
+        self
+    }
+
+    /// Registers a hook which is invoked before each top-level block is formatted.
+    ///
+    /// The hook receives the block and the underlying writer and may write arbitrary bytes, e.g.
+    /// a comment containing provenance information. Anything written by the hook is placed
+    /// directly before the block identifier, after the empty line that separates the block from
+    /// preceding structures. The hook is responsible for terminating its output with a newline.
+    ///
+    /// Nested blocks do not invoke the hook.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// #
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use hcl::format::{Format, Formatter};
+    /// use std::io::Write;
+    ///
+    /// let mut formatter = Formatter::builder()
+    ///     .before_block(|block, writer| {
+    ///         writeln!(writer, "# managed by tool, block `{}`", block.identifier())
+    ///     })
+    ///     .build_vec();
+    ///
+    /// let body = hcl::body!({ resource "aws_s3_bucket" "bucket" {} });
+    ///
+    /// assert_eq!(
+    ///     body.format_string(&mut formatter)?,
+    ///     "# managed by tool, block `resource`\nresource \"aws_s3_bucket\" \"bucket\" {}\n"
+    /// );
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn before_block<F>(mut self, f: F) -> Self
+    where
+        F: FnMut(&Block, &mut dyn io::Write) -> io::Result<()> + 'a,
+    {
+        self.config.before_block = Some(Box::new(f));
+        self
+    }
+
+    /// Registers a hook which is invoked after each top-level block is formatted.
+    ///
+    /// The hook receives the block and the underlying writer and may write arbitrary bytes.
+    /// Anything written by the hook is placed directly after the newline that terminates the
+    /// closing brace of the block.
+    ///
+    /// Nested blocks do not invoke the hook. See [`before_block`][FormatterBuilder::before_block]
+    /// for an example.
+    pub fn after_block<F>(mut self, f: F) -> Self
+    where
+        F: FnMut(&Block, &mut dyn io::Write) -> io::Result<()> + 'a,
+    {
+        self.config.after_block = Some(Box::new(f));
         self
     }
 
@@ -318,6 +767,9 @@ impl<'a> FormatterBuilder<'a> {
             current_indent: 0,
             has_value: false,
             compact_mode_level: 0,
+            bytes_written: 0,
+            attribute_key_width: 0,
+            bom_written: false,
         }
     }
 
@@ -393,6 +845,12 @@ where
     pub fn into_inner(self) -> W {
         self.writer
     }
+
+    /// Returns the total number of bytes the `Formatter` has written to the underlying writer so
+    /// far, including any bytes written by block hooks.
+    pub fn bytes_written(&self) -> usize {
+        self.bytes_written
+    }
 }
 
 // Internal formatter API.
@@ -430,21 +888,36 @@ where
     fn write_quoted_string(&mut self, s: &str, escape: bool) -> Result<()> {
         self.write_bytes(b"\"")?;
         if escape {
-            self.write_escaped_string(s)?;
+            self.write_escaped_string(s, false)?;
         } else {
             self.write_string_fragment(s)?;
         }
         self.write_bytes(b"\"")
     }
 
+    /// Writes a string value as quoted string to the writer.
+    ///
+    /// Unless template sequences are escaped, strings containing them are written verbatim so
+    /// that they are interpreted as templates.
+    fn write_quoted_string_value(&mut self, s: &str) -> Result<()> {
+        if self.config.escape_template_sequences {
+            self.write_bytes(b"\"")?;
+            self.write_escaped_string(s, true)?;
+            self.write_bytes(b"\"")
+        } else {
+            self.write_quoted_string(s, !is_templated(s))
+        }
+    }
+
     /// Writes a string fragment to the writer. No escaping occurs.
     fn write_string_fragment(&mut self, s: &str) -> Result<()> {
         self.write_bytes(s.as_bytes())
     }
 
     /// Writes a string to the writer and escapes control characters and quotes that might be
-    /// contained in it.
-    fn write_escaped_string(&mut self, value: &str) -> Result<()> {
+    /// contained in it. Template sequence introductions (`${` and `%{`) are escaped as well if
+    /// `escape_templates` is true.
+    fn write_escaped_string(&mut self, value: &str, escape_templates: bool) -> Result<()> {
         let bytes = value.as_bytes();
 
         let mut start = 0;
@@ -452,6 +925,16 @@ where
         for (i, &byte) in bytes.iter().enumerate() {
             let escape = ESCAPE[byte as usize];
             if escape == 0 {
+                if escape_templates
+                    && matches!(byte, b'$' | b'%')
+                    && bytes.get(i + 1) == Some(&b'{')
+                {
+                    // Template sequence introductions are escaped by doubling the `$` or `%`.
+                    // Write everything including the current byte and let the next fragment
+                    // start at the same byte again.
+                    self.write_string_fragment(&value[start..=i])?;
+                    start = i;
+                }
                 continue;
             }
 
@@ -460,7 +943,10 @@ where
             }
 
             let char_escape = CharEscape::from_escape_table(escape, byte);
-            char_escape.write_escaped(&mut self.writer)?;
+            char_escape.write_escaped(&mut CountingWriter {
+                inner: &mut self.writer,
+                count: &mut self.bytes_written,
+            })?;
 
             start = i + 1;
         }
@@ -552,7 +1038,7 @@ where
 
     /// Signals the start of an object value to the formatter.
     fn begin_object_value(&mut self) -> Result<()> {
-        self.write_bytes(b" = ")
+        self.write_bytes(self.config.assignment)
     }
 
     /// Signals the end of an object value to the formatter.
@@ -578,15 +1064,28 @@ where
         self.write_bytes(b"}")
     }
 
-    /// Signals the start of an attribute to the formatter.
-    fn begin_attribute(&mut self) -> Result<()> {
+    /// Signals the start of an attribute with an optional leading comment to the formatter.
+    fn begin_attribute(&mut self, comment: Option<&str>) -> Result<()> {
         self.maybe_write_newline(FormatState::AttributeStart)?;
+        self.write_comment(comment)?;
         self.write_indent(self.current_indent)
     }
 
+    /// Pads the attribute key to the width of the longest key of its attribute run if attribute
+    /// alignment is enabled.
+    fn align_attribute_key(&mut self, key: &str) -> Result<()> {
+        let width = std::mem::take(&mut self.attribute_key_width);
+
+        for _ in key.chars().count()..width {
+            self.write_bytes(b" ")?;
+        }
+
+        Ok(())
+    }
+
     /// Signals the start of an attribute value to the formatter.
     fn begin_attribute_value(&mut self) -> Result<()> {
-        self.write_bytes(b" = ")
+        self.write_bytes(self.config.assignment)
     }
 
     /// Signals the end of an attribute to the formatter.
@@ -595,9 +1094,24 @@ where
         self.write_bytes(b"\n")
     }
 
-    /// Signals the start of a block to the formatter.
-    fn begin_block(&mut self) -> Result<()> {
+    /// Signals the start of a block with an optional leading comment to the formatter. Top-level
+    /// block hooks are invoked with `block` if it is `Some`.
+    fn begin_block(&mut self, block: Option<&Block>, comment: Option<&str>) -> Result<()> {
         self.maybe_write_newline(FormatState::BlockStart)?;
+
+        if let (0, Some(block)) = (self.current_indent, block) {
+            if let Some(hook) = &mut self.config.before_block {
+                hook(
+                    block,
+                    &mut CountingWriter {
+                        inner: &mut self.writer,
+                        count: &mut self.bytes_written,
+                    },
+                )?;
+            }
+        }
+
+        self.write_comment(comment)?;
         self.write_indent(self.current_indent)
     }
 
@@ -608,12 +1122,32 @@ where
         self.write_bytes(b" {")
     }
 
-    /// Signals the end of a block to the formatter.
-    fn end_block(&mut self) -> Result<()> {
-        self.state = FormatState::BlockEnd;
+    /// Signals the end of a block to the formatter. Top-level block hooks are invoked with
+    /// `block` if it is `Some`.
+    fn end_block(&mut self, block: Option<&Block>) -> Result<()> {
         self.current_indent -= 1;
-        self.write_indent(self.current_indent)?;
-        self.write_bytes(b"}\n")
+
+        // Empty block bodies are closed on the same line, e.g. `block {}`.
+        if self.state != FormatState::BlockBodyStart {
+            self.write_indent(self.current_indent)?;
+        }
+
+        self.state = FormatState::BlockEnd;
+        self.write_bytes(b"}\n")?;
+
+        if let (0, Some(block)) = (self.current_indent, block) {
+            if let Some(hook) = &mut self.config.after_block {
+                hook(
+                    block,
+                    &mut CountingWriter {
+                        inner: &mut self.writer,
+                        count: &mut self.bytes_written,
+                    },
+                )?;
+            }
+        }
+
+        Ok(())
     }
 
     // Conditionally writes a newline character depending on the formatter configuration and the
@@ -640,9 +1174,33 @@ where
         Ok(())
     }
 
+    // Writes each line of `comment` as an indented `#` line comment.
+    fn write_comment(&mut self, comment: Option<&str>) -> Result<()> {
+        let Some(comment) = comment else {
+            return Ok(());
+        };
+
+        for line in comment.lines() {
+            self.write_indent(self.current_indent)?;
+
+            let line = line.trim_end();
+
+            if line.is_empty() {
+                self.write_bytes(b"#\n")?;
+            } else {
+                self.write_bytes(b"# ")?;
+                self.write_bytes(line.as_bytes())?;
+                self.write_bytes(b"\n")?;
+            }
+        }
+
+        Ok(())
+    }
+
     fn write_indent(&mut self, n: usize) -> Result<()> {
         for _ in 0..n {
-            self.write_bytes(self.config.indent)?;
+            self.writer.write_all(&self.config.indent)?;
+            self.bytes_written += self.config.indent.len();
         }
 
         Ok(())
@@ -667,6 +1225,7 @@ where
 
     fn write_bytes(&mut self, buf: &[u8]) -> Result<()> {
         self.writer.write_all(buf)?;
+        self.bytes_written += buf.len();
         Ok(())
     }
 
@@ -684,6 +1243,50 @@ where
         result
     }
 
+    // Returns `true` if an attribute with the given key and string value should preferably be
+    // formatted as heredoc.
+    fn prefers_heredoc(&self, key: &str, value: &str) -> bool {
+        (self.config.multiline_heredocs && value.contains('\n'))
+            || self.exceeds_max_line_width(key, value)
+    }
+
+    // Returns `true` if an attribute with the given key and quoted string value exceeds the
+    // configured maximum line width.
+    fn exceeds_max_line_width(&self, key: &str, value: &str) -> bool {
+        let Some(max) = self.config.max_line_width else {
+            return false;
+        };
+
+        let indent = self.current_indent * self.config.indent.len();
+        // The `key = ` part plus the surrounding quotes.
+        let assignment = String::from_utf8_lossy(self.config.assignment)
+            .chars()
+            .count();
+        let overhead = key.chars().count() + assignment + 2;
+        let escape_templates = self.config.escape_template_sequences;
+        let escape = escape_templates || !is_templated(value);
+        let template_escapes = if escape_templates {
+            value.matches("${").count() + value.matches("%{").count()
+        } else {
+            0
+        };
+
+        let value_width: usize = value
+            .chars()
+            .map(
+                |ch| match u8::try_from(ch).map(|byte| ESCAPE[byte as usize]) {
+                    Ok(0) | Err(_) => 1,
+                    Ok(_) if !escape => 1,
+                    Ok(escape::UU) => 6,
+                    Ok(_) => 2,
+                },
+            )
+            .sum::<usize>()
+            + template_escapes;
+
+        indent + overhead + value_width > max
+    }
+
     fn compact_arrays(&self) -> bool {
         self.config.compact_arrays || self.in_compact_mode()
     }
@@ -15,6 +15,9 @@ impl Identifier {
     /// Create a new `Identifier` after validating that it only contains characters that are
     /// allowed in HCL identifiers.
     ///
+    /// A valid identifier starts with a Unicode `ID_Start` character or an underscore, followed
+    /// by any number of Unicode `ID_Continue` characters or dashes (`-`).
+    ///
     /// See [`Identifier::sanitized`][Identifier::sanitized] for an infallible alternative to this
     /// function.
     ///
@@ -23,6 +26,7 @@ impl Identifier {
     /// ```
     /// # use hcl::Identifier;
     /// assert!(Identifier::new("some_ident").is_ok());
+    /// assert!(Identifier::new("some-ident").is_ok());
     /// assert!(Identifier::new("").is_err());
     /// assert!(Identifier::new("1two3").is_err());
     /// assert!(Identifier::new("with whitespace").is_err());
@@ -97,7 +101,9 @@ impl Identifier {
 
     /// Create a new `Identifier` without checking if it is valid.
     ///
-    /// It is the caller's responsibility to ensure that the identifier is valid.
+    /// This skips the validation performed by [`Identifier::new`], which avoids its overhead
+    /// when the input is known to be valid, e.g. because it is a string literal or was produced
+    /// by the parser. It is the caller's responsibility to ensure that the identifier is valid.
     ///
     /// For most use cases [`Identifier::new`][Identifier::new] or
     /// [`Identifier::sanitized`][Identifier::sanitized] should be preferred.
@@ -173,3 +179,24 @@ impl Borrow<str> for Identifier {
         self.as_str()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        for valid in ["foo", "_bar", "a-b", "a1", "_", "føø", "名前", "x-_-9"] {
+            assert_eq!(Identifier::new(valid).unwrap().as_str(), valid);
+        }
+
+        for invalid in [
+            "", "1foo", "foo bar", "-foo", "foo.bar", "foo\n", "a=b", "\"foo\"",
+        ] {
+            assert!(
+                matches!(Identifier::new(invalid), Err(Error::InvalidIdentifier(ref s)) if s == invalid),
+                "{invalid:?} should be rejected"
+            );
+        }
+    }
+}
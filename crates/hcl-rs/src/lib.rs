@@ -33,6 +33,7 @@ mod ident;
 mod internal_string;
 mod number;
 mod parser;
+pub mod prelude;
 pub mod ser;
 pub mod structure;
 pub mod template;
@@ -63,10 +64,10 @@ pub use expr::{
 
 pub use ident::Identifier;
 pub use number::Number;
-pub use parser::parse;
+pub use parser::{parse, parse_with_options, ParseOptions};
 
 #[doc(inline)]
-pub use ser::{to_string, to_vec, to_writer};
+pub use ser::{to_string, to_string_with, to_vec, to_writer, to_writer_with};
 
 #[doc(inline)]
 pub use structure::{Attribute, Block, BlockLabel, Body, Structure};
@@ -256,10 +256,7 @@ macro_rules! attribute_internal {
 
     // Attribute with expression as key.
     (($key:expr) = $($expr:tt)+) => {
-        $crate::Attribute {
-            key: ($key).into(),
-            expr: $crate::expression_internal!($($expr)+),
-        }
+        $crate::Attribute::new($key, $crate::expression_internal!($($expr)+))
     };
 
     // Attribute with identifier as key.
@@ -322,10 +319,11 @@ macro_rules! block_internal {
 
     // Found block body, done.
     (($ident:expr) [$(($labels:expr))*] {$($body:tt)*}) => {
-        $crate::Block {
-            identifier: ($ident).into(),
-            labels: std::vec![$($labels),*],
-            body: $crate::body!($($body)*),
+        {
+            let mut block = $crate::Block::new($ident);
+            block.labels = std::vec![$($labels),*];
+            block.body = $crate::body!($($body)*);
+            block
         }
     };
 
@@ -4,10 +4,48 @@ use serde::{forward_to_deserialize_any, ser};
 use std::cmp::Ordering;
 use std::fmt;
 use std::hash::{Hash, Hasher};
-use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+use std::str::FromStr;
 
 /// Represents an HCL number.
-#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd)]
+///
+/// Integers are stored losslessly, either as unsigned 64-bit integer or, if negative, as signed
+/// 64-bit integer. All other numbers are stored as finite 64-bit floats.
+///
+/// When serializing Rust values via serde, the number type of the input decides about the
+/// representation: all signed and unsigned integers up to 64 bits are converted into their exact
+/// integer representation (e.g. `-1i32` becomes `-1` and `u64::MAX` becomes
+/// `18446744073709551615`), while `f32` and `f64` are always converted into floats (e.g. `1.0f64`
+/// becomes `1.0`). `f32` values are converted via their shortest decimal representation, so
+/// `0.1f32` becomes `0.1` (see [`Number::from_f32`]). Non-finite floats are not valid HCL numbers
+/// and are serialized as `null`. Integer-valued floats are not converted into integers
+/// automatically, use [`Number::normalized`] for that.
+///
+/// Numbers are totally ordered by their value. Integers and floats are compared exactly, so e.g.
+/// `1` is less than `1.5`, and `2` is equal to `2.0`.
+///
+/// The [`Ord`] methods `min`, `max` and `clamp` return the operand of the appropriate variant
+/// unchanged, so the minimum of the integer `1` and the float `2.0` is the integer `1`. If two
+/// numbers of different variants are equal, `min` returns `self` and `max` returns `other`.
+///
+/// ```
+/// use hcl::Number;
+///
+/// let int = Number::from(1u64);
+/// let float = Number::from_f64(1.0).unwrap();
+///
+/// assert!(int.min(Number::from_f64(2.0).unwrap()).is_u64());
+/// assert!(int.min(float).is_u64());
+/// assert!(int.max(float).is_f64());
+/// assert_eq!(Number::from(-5).clamp(int, float), int);
+/// ```
+///
+/// Formatting a `Number` never depends on the process locale: floats always use `.` as decimal
+/// separator and no digit grouping is applied, so the output is always a valid HCL numeric
+/// literal.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Number {
     n: N,
 }
@@ -44,6 +82,25 @@ impl N {
         }
     }
 
+    fn as_i128(&self) -> Option<i128> {
+        match *self {
+            N::PosInt(n) => Some(n.into()),
+            N::NegInt(n) => Some(n.into()),
+            N::Float(_) => None,
+        }
+    }
+
+    // Integers that do not fit into the range of `i64::MIN..=u64::MAX` are converted to floats.
+    fn from_i128(i: i128) -> N {
+        if let Ok(u) = u64::try_from(i) {
+            N::PosInt(u)
+        } else if let Ok(i) = i64::try_from(i) {
+            N::NegInt(i)
+        } else {
+            N::Float(i as f64)
+        }
+    }
+
     #[allow(clippy::wrong_self_convention)]
     fn to_f64(&self) -> f64 {
         match *self {
@@ -56,12 +113,7 @@ impl N {
 
 impl PartialEq for N {
     fn eq(&self, other: &Self) -> bool {
-        match (self, other) {
-            (N::PosInt(a), N::PosInt(b)) => a == b,
-            (N::NegInt(a), N::NegInt(b)) => a == b,
-            (N::Float(a), N::Float(b)) => a == b,
-            (a, b) => a.to_f64() == b.to_f64(),
-        }
+        self.cmp(other) == Ordering::Equal
     }
 }
 
@@ -70,15 +122,46 @@ impl Eq for N {}
 
 impl PartialOrd for N {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        match (*self, *other) {
-            (N::PosInt(a), N::PosInt(b)) => a.partial_cmp(&b),
-            (N::NegInt(a), N::NegInt(b)) => a.partial_cmp(&b),
-            (N::Float(a), N::Float(b)) => a.partial_cmp(&b),
-            (a, b) => a.to_f64().partial_cmp(&b.to_f64()),
+        Some(self.cmp(other))
+    }
+}
+
+// N is `Ord` because we ensure that the wrapped f64 is always finite, so comparing floats never
+// yields `None`. Integers and floats are compared by their exact value instead of converting the
+// integer to `f64`, which would be lossy and make the ordering intransitive.
+impl Ord for N {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.as_i128(), other.as_i128()) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            (Some(a), None) => cmp_int_float(a, other.to_f64()),
+            (None, Some(b)) => cmp_int_float(b, self.to_f64()).reverse(),
+            (None, None) => self
+                .to_f64()
+                .partial_cmp(&other.to_f64())
+                .unwrap_or(Ordering::Equal),
         }
     }
 }
 
+// Compares an integer in the range of `i64::MIN..=u64::MAX` with a finite float.
+fn cmp_int_float(i: i128, f: f64) -> Ordering {
+    // 2^64 is exactly representable as `f64` and greater than all integers in range.
+    const LIMIT: f64 = 18_446_744_073_709_551_616.0;
+
+    if f >= LIMIT {
+        Ordering::Less
+    } else if f < -LIMIT {
+        Ordering::Greater
+    } else {
+        // The truncated float fits into an `i128` without loss. If the integer parts are equal,
+        // the fractional part of the float decides.
+        let trunc = f.trunc();
+
+        i.cmp(&(trunc as i128))
+            .then_with(|| trunc.partial_cmp(&f).unwrap_or(Ordering::Equal))
+    }
+}
+
 impl Hash for N {
     fn hash<H>(&self, h: &mut H)
     where
@@ -126,6 +209,28 @@ impl Number {
             None
         }
     }
+
+    /// Creates a new `Number` from a `f32`. Returns `None` if the float is infinite or NaN.
+    ///
+    /// Widening an `f32` to `f64` exposes its binary approximation of decimal values (e.g.
+    /// `0.1f32 as f64` is `0.10000000149011612`). To avoid this, the float is converted via its
+    /// shortest decimal representation which round-trips to the same `f32`.
+    ///
+    /// ```
+    /// use hcl::Number;
+    ///
+    /// assert_eq!(Number::from_f32(0.1).unwrap().to_string(), "0.1");
+    /// assert!(Number::from_f32(f32::NAN).is_none());
+    /// ```
+    pub fn from_f32(f: f32) -> Option<Number> {
+        if f.is_finite() {
+            // The `Display` impl of `f32` emits the shortest representation that round-trips.
+            Number::from_f64(f.to_string().parse().ok()?)
+        } else {
+            None
+        }
+    }
+
     /// Represents the `Number` as f64 if possible. Returns None otherwise.
     pub fn as_f64(&self) -> Option<f64> {
         Some(self.n.to_f64())
@@ -141,6 +246,38 @@ impl Number {
         self.n.as_u64()
     }
 
+    /// If the `Number` is an integer, represent it as i128. Returns None otherwise.
+    ///
+    /// Integers are stored in the range of `i64::MIN..=u64::MAX`, so this never returns `None`
+    /// for integers. Integers outside of this range, e.g. when parsed from HCL or produced by
+    /// arithmetic, are represented as floats and lose precision; this returns `None` for them.
+    ///
+    /// ```
+    /// use hcl::Number;
+    ///
+    /// assert_eq!(Number::from(i64::MIN).as_i128(), Some(i64::MIN.into()));
+    /// assert_eq!(Number::from(u64::MAX).as_i128(), Some(u64::MAX.into()));
+    /// assert_eq!(Number::from_f64(1.0).unwrap().as_i128(), None);
+    /// ```
+    pub fn as_i128(&self) -> Option<i128> {
+        self.n.as_i128()
+    }
+
+    /// If the `Number` is a non-negative integer, represent it as u128. Returns None otherwise.
+    ///
+    /// The same precision ceiling as for [`Number::as_i128`] applies: integers above `u64::MAX`
+    /// are represented as floats and this returns `None` for them.
+    ///
+    /// ```
+    /// use hcl::Number;
+    ///
+    /// assert_eq!(Number::from(u64::MAX).as_u128(), Some(u64::MAX.into()));
+    /// assert_eq!(Number::from(-1).as_u128(), None);
+    /// ```
+    pub fn as_u128(&self) -> Option<u128> {
+        self.n.as_u64().map(u128::from)
+    }
+
     /// Returns true if the `Number` is a float.
     ///
     /// For any `Number` on which `is_f64` returns true, `as_f64` is guaranteed to return the
@@ -175,6 +312,148 @@ impl Number {
         }
     }
 
+    /// Converts an integer-valued float into an integer `Number`. Other numbers are returned
+    /// unchanged.
+    ///
+    /// Floats are always formatted with a fractional part, so `Number::from_f64(2.0)` is
+    /// serialized as `2.0`. The serializer never applies this conversion on its own, call
+    /// `normalized` before serializing if `2` is preferred. Floats outside of the range
+    /// `i64::MIN..=u64::MAX` are kept as is.
+    ///
+    /// ```
+    /// use hcl::Number;
+    ///
+    /// let n = Number::from_f64(2.0).unwrap();
+    /// assert_eq!(n.to_string(), "2.0");
+    /// assert_eq!(n.normalized().to_string(), "2");
+    /// assert!(Number::from_f64(-4.0).unwrap().normalized().is_i64());
+    /// assert!(Number::from_f64(2.5).unwrap().normalized().is_f64());
+    /// ```
+    pub fn normalized(self) -> Number {
+        match self.n {
+            // The upper bound is `2^64`, which is exactly representable as `f64`, while
+            // `u64::MAX` is not.
+            N::Float(f)
+                if f.fract() == 0.0 && f >= i64::MIN as f64 && f < 18_446_744_073_709_551_616.0 =>
+            {
+                Number {
+                    n: N::from_i128(f as i128),
+                }
+            }
+            _ => self,
+        }
+    }
+
+    /// Checked addition. Returns `None` if the result is not a finite number.
+    ///
+    /// If both numbers are integers, the result stays an integer as long as it fits into the
+    /// range of `i64::MIN..=u64::MAX` and is promoted to a float otherwise.
+    ///
+    /// ```
+    /// use hcl::Number;
+    ///
+    /// assert_eq!(Number::from(1u64).checked_add(Number::from(2u64)), Some(Number::from(3u64)));
+    /// assert!(Number::from(u64::MAX).checked_add(Number::from(1u64)).unwrap().is_f64());
+    ///
+    /// let huge = Number::from_f64(f64::MAX).unwrap();
+    /// assert_eq!(huge.checked_add(huge), None);
+    /// ```
+    pub fn checked_add(self, rhs: Number) -> Option<Number> {
+        match (self.n.as_i128(), rhs.n.as_i128()) {
+            (Some(a), Some(b)) => Some(Number {
+                n: N::from_i128(a + b),
+            }),
+            (_, _) => Number::from_f64(self.n.to_f64() + rhs.n.to_f64()),
+        }
+    }
+
+    /// Checked subtraction. Returns `None` if the result is not a finite number.
+    ///
+    /// Uses the same integer promotion rules as [`Number::checked_add`].
+    ///
+    /// ```
+    /// use hcl::Number;
+    ///
+    /// assert_eq!(Number::from(1u64).checked_sub(Number::from(2u64)), Some(Number::from(-1)));
+    ///
+    /// let negated = Number::from(0u64).checked_sub(Number::from(i64::MIN));
+    /// assert_eq!(negated, Some(Number::from(1u64 << 63)));
+    /// ```
+    pub fn checked_sub(self, rhs: Number) -> Option<Number> {
+        match (self.n.as_i128(), rhs.n.as_i128()) {
+            (Some(a), Some(b)) => Some(Number {
+                n: N::from_i128(a - b),
+            }),
+            (_, _) => Number::from_f64(self.n.to_f64() - rhs.n.to_f64()),
+        }
+    }
+
+    /// Checked multiplication. Returns `None` if the result is not a finite number.
+    ///
+    /// Uses the same integer promotion rules as [`Number::checked_add`].
+    ///
+    /// ```
+    /// use hcl::Number;
+    ///
+    /// assert_eq!(Number::from(-2).checked_mul(Number::from(3u64)), Some(Number::from(-6)));
+    /// assert!(Number::from(i64::MIN).checked_mul(Number::from(2u64)).unwrap().is_f64());
+    /// ```
+    pub fn checked_mul(self, rhs: Number) -> Option<Number> {
+        match (self.n.as_i128(), rhs.n.as_i128()) {
+            (Some(a), Some(b)) => match a.checked_mul(b) {
+                Some(n) => Some(Number { n: N::from_i128(n) }),
+                None => Number::from_f64(a as f64 * b as f64),
+            },
+            (_, _) => Number::from_f64(self.n.to_f64() * rhs.n.to_f64()),
+        }
+    }
+
+    /// Checked division. Returns `None` if `rhs` is zero or if the result is not a finite
+    /// number.
+    ///
+    /// If both numbers are integers and the division has no remainder, the result is an integer.
+    /// Otherwise it is a float.
+    ///
+    /// ```
+    /// use hcl::Number;
+    ///
+    /// assert_eq!(Number::from(-4).checked_div(Number::from(2u64)), Some(Number::from(-2)));
+    /// assert_eq!(Number::from(1u64).checked_div(Number::from(2u64)), Number::from_f64(0.5));
+    /// assert_eq!(Number::from(1u64).checked_div(Number::from(0u64)), None);
+    /// ```
+    pub fn checked_div(self, rhs: Number) -> Option<Number> {
+        match (self.n.as_i128(), rhs.n.as_i128()) {
+            (_, Some(0)) => None,
+            (Some(a), Some(b)) if a % b == 0 => Some(Number {
+                n: N::from_i128(a / b),
+            }),
+            (_, _) => Number::from_f64(self.n.to_f64() / rhs.n.to_f64()),
+        }
+    }
+
+    /// Checked remainder. Returns `None` if `rhs` is zero or if the result is not a finite
+    /// number.
+    ///
+    /// If both numbers are integers, the result is an integer. Otherwise it is a float.
+    ///
+    /// ```
+    /// use hcl::Number;
+    ///
+    /// assert_eq!(Number::from(-7).checked_rem(Number::from(2u64)), Some(Number::from(-1)));
+    /// let rem = Number::from_f64(2.5).unwrap().checked_rem(Number::from(2u64));
+    /// assert_eq!(rem, Number::from_f64(0.5));
+    /// assert_eq!(Number::from(1u64).checked_rem(Number::from(0u64)), None);
+    /// ```
+    pub fn checked_rem(self, rhs: Number) -> Option<Number> {
+        match (self.n.as_i128(), rhs.n.as_i128()) {
+            (_, Some(0)) => None,
+            (Some(a), Some(b)) => Some(Number {
+                n: N::from_i128(a % b),
+            }),
+            (_, _) => Number::from_f64(self.n.to_f64() % rhs.n.to_f64()),
+        }
+    }
+
     #[cold]
     pub(crate) fn unexpected(&self) -> Unexpected {
         match self.n {
@@ -216,6 +495,77 @@ macro_rules! impl_from_signed {
 impl_from_unsigned!(u8, u16, u32, u64, usize);
 impl_from_signed!(i8, i16, i32, i64, isize);
 
+impl FromStr for Number {
+    type Err = Error;
+
+    /// Parses a `Number` from a decimal numeric literal with an optional leading `-`.
+    ///
+    /// The presence of a decimal point or an exponent always yields a float, so `42.0` and
+    /// `4.2e1` are not collapsed into integers. Otherwise the integer representation is chosen by
+    /// sign. Integers do not have a negative zero, so `-0` yields the same integer as `0`.
+    /// Integers exceeding the range of `i64::MIN..=u64::MAX` are represented as floats.
+    ///
+    /// Only the decimal numeric syntax accepted by HCL itself is supported. Notations that HCL
+    /// does not support, like hexadecimal (`0x1F`), a leading `+` or `inf`, are rejected, as are
+    /// floats that overflow to infinity (e.g. `1e400`).
+    ///
+    /// ```
+    /// use hcl::Number;
+    ///
+    /// assert!("42".parse::<Number>()?.is_u64());
+    /// assert!("-42".parse::<Number>()?.is_i64());
+    /// assert!("42.0".parse::<Number>()?.is_f64());
+    /// assert!("4.2e1".parse::<Number>()?.is_f64());
+    /// assert!("forty-two".parse::<Number>().is_err());
+    /// # Ok::<(), hcl::Error>(())
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::new(format!("invalid number `{s}`"));
+
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, s),
+        };
+
+        let (mantissa, exponent) = match digits.find(['e', 'E']) {
+            Some(pos) => (&digits[..pos], Some(&digits[pos + 1..])),
+            None => (digits, None),
+        };
+
+        let (int, fraction) = match mantissa.split_once('.') {
+            Some((int, fraction)) => (int, Some(fraction)),
+            None => (mantissa, None),
+        };
+
+        let is_decimal = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+
+        if !is_decimal(int)
+            || fraction.is_some_and(|fraction| !is_decimal(fraction))
+            || exponent.is_some_and(|exp| !is_decimal(exp.strip_prefix(['+', '-']).unwrap_or(exp)))
+        {
+            return Err(invalid());
+        }
+
+        if fraction.is_none() && exponent.is_none() {
+            if let Ok(u) = int.parse::<u64>() {
+                let n = if negative {
+                    N::from_i128(-i128::from(u))
+                } else {
+                    N::PosInt(u)
+                };
+
+                return Ok(Number { n });
+            }
+        }
+
+        // Floats and integers exceeding the range of `u64`.
+        s.parse::<f64>()
+            .ok()
+            .and_then(Number::from_f64)
+            .ok_or_else(invalid)
+    }
+}
+
 impl fmt::Display for Number {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         match self.n {
@@ -305,15 +655,8 @@ impl Neg for Number {
 
     fn neg(self) -> Self::Output {
         let n = match self.n {
-            N::PosInt(value) => N::NegInt(-(value as i64)),
-            N::NegInt(value) => {
-                let value = -value;
-                if value < 0 {
-                    N::NegInt(value)
-                } else {
-                    N::PosInt(value as u64)
-                }
-            }
+            N::PosInt(value) => N::from_i128(-i128::from(value)),
+            N::NegInt(value) => N::from_i128(-i128::from(value)),
             N::Float(value) => N::Float(-value),
         };
 
@@ -325,13 +668,9 @@ impl Add for Number {
     type Output = Number;
 
     fn add(self, rhs: Self) -> Self::Output {
-        let n = match (self.n, rhs.n) {
-            (N::PosInt(a), N::PosInt(b)) => N::PosInt(a + b),
-            (N::PosInt(a), N::NegInt(b)) => N::from(a as i64 + b),
-            (N::NegInt(a), N::NegInt(b)) => N::from(a + b),
-            (N::NegInt(a), N::PosInt(b)) => N::from(a + b as i64),
-            (N::Float(a), N::Float(b)) => N::Float(a + b),
-            (a, b) => N::Float(a.to_f64() + b.to_f64()),
+        let n = match (self.n.as_i128(), rhs.n.as_i128()) {
+            (Some(a), Some(b)) => N::from_i128(a + b),
+            (_, _) => N::Float(self.n.to_f64() + rhs.n.to_f64()),
         };
 
         Number { n }
@@ -342,19 +681,9 @@ impl Sub for Number {
     type Output = Number;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        let n = match (self.n, rhs.n) {
-            (N::PosInt(a), N::PosInt(b)) => {
-                if a < b {
-                    N::NegInt(a as i64 - b as i64)
-                } else {
-                    N::PosInt(a - b)
-                }
-            }
-            (N::PosInt(a), N::NegInt(b)) => N::from(a as i64 - b),
-            (N::NegInt(a), N::NegInt(b)) => N::from(a - b),
-            (N::NegInt(a), N::PosInt(b)) => N::from(a - b as i64),
-            (N::Float(a), N::Float(b)) => N::Float(a - b),
-            (a, b) => N::Float(a.to_f64() - b.to_f64()),
+        let n = match (self.n.as_i128(), rhs.n.as_i128()) {
+            (Some(a), Some(b)) => N::from_i128(a - b),
+            (_, _) => N::Float(self.n.to_f64() - rhs.n.to_f64()),
         };
 
         Number { n }
@@ -365,13 +694,12 @@ impl Mul for Number {
     type Output = Number;
 
     fn mul(self, rhs: Self) -> Self::Output {
-        let n = match (self.n, rhs.n) {
-            (N::PosInt(a), N::PosInt(b)) => N::PosInt(a * b),
-            (N::PosInt(a), N::NegInt(b)) => N::from(a as i64 * b),
-            (N::NegInt(a), N::NegInt(b)) => N::from(a * b),
-            (N::NegInt(a), N::PosInt(b)) => N::from(a * b as i64),
-            (N::Float(a), N::Float(b)) => N::Float(a * b),
-            (a, b) => N::Float(a.to_f64() * b.to_f64()),
+        let n = match (self.n.as_i128(), rhs.n.as_i128()) {
+            (Some(a), Some(b)) => match a.checked_mul(b) {
+                Some(n) => N::from_i128(n),
+                None => N::Float(a as f64 * b as f64),
+            },
+            (_, _) => N::Float(self.n.to_f64() * rhs.n.to_f64()),
         };
 
         Number { n }
@@ -403,19 +731,35 @@ impl Rem for Number {
     type Output = Number;
 
     fn rem(self, rhs: Self) -> Self::Output {
-        let n = match (self.n, rhs.n) {
-            (N::PosInt(a), N::PosInt(b)) => N::PosInt(a % b),
-            (N::PosInt(a), N::NegInt(b)) => N::from(a as i64 % b),
-            (N::NegInt(a), N::NegInt(b)) => N::from(a % b),
-            (N::NegInt(a), N::PosInt(b)) => N::from(a % b as i64),
-            (N::Float(a), N::Float(b)) => N::Float(a % b),
-            (a, b) => N::Float(a.to_f64() % b.to_f64()),
+        let n = match (self.n.as_i128(), rhs.n.as_i128()) {
+            (Some(a), Some(b)) => N::from_i128(a % b),
+            (_, _) => N::Float(self.n.to_f64() % rhs.n.to_f64()),
         };
 
         Number { n }
     }
 }
 
+macro_rules! impl_assign_op {
+    ($($assign_trait:ident::$assign_method:ident => $trait:ident::$method:ident),*) => {
+        $(
+            impl $assign_trait for Number {
+                fn $assign_method(&mut self, rhs: Self) {
+                    *self = $trait::$method(*self, rhs);
+                }
+            }
+        )*
+    };
+}
+
+impl_assign_op! {
+    AddAssign::add_assign => Add::add,
+    SubAssign::sub_assign => Sub::sub,
+    MulAssign::mul_assign => Mul::mul,
+    DivAssign::div_assign => Div::div,
+    RemAssign::rem_assign => Rem::rem
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -444,4 +788,320 @@ mod tests {
             Number::from(-2)
         );
     }
+
+    #[test]
+    fn normalized() {
+        assert_eq!(
+            Number::from_f64(2.0).unwrap().normalized(),
+            Number::from(2u64)
+        );
+        assert!(Number::from_f64(2.0).unwrap().normalized().is_u64());
+        assert!(Number::from_f64(-0.0).unwrap().normalized().is_u64());
+        assert!(Number::from_f64(-4.0).unwrap().normalized().is_i64());
+        assert!(Number::from_f64(2.5).unwrap().normalized().is_f64());
+        assert!(Number::from_f64(1e20).unwrap().normalized().is_f64());
+        assert!(Number::from(7u64).normalized().is_u64());
+    }
+
+    #[test]
+    fn assign_ops() {
+        let numbers = [
+            Number::from(7u64),
+            Number::from(-3i64),
+            Number::from_f64(2.5).unwrap(),
+            Number::from_f64(-4.0).unwrap(),
+        ];
+
+        for a in numbers {
+            for b in numbers {
+                let mut n = a;
+                n += b;
+                assert_eq!(n, a + b);
+
+                let mut n = a;
+                n -= b;
+                assert_eq!(n, a - b);
+
+                let mut n = a;
+                n *= b;
+                assert_eq!(n, a * b);
+
+                let mut n = a;
+                n /= b;
+                assert_eq!(n, a / b);
+
+                let mut n = a;
+                n %= b;
+                assert_eq!(n, a % b);
+            }
+        }
+
+        let mut sum = Number::from(1u64);
+        sum += Number::from(2u64);
+        assert!(sum.is_u64());
+        sum += Number::from_f64(0.5).unwrap();
+        assert_eq!(sum, Number::from_f64(3.5).unwrap());
+    }
+
+    #[test]
+    fn large_integers() {
+        let max = Number::from(u64::MAX);
+        let min = Number::from(i64::MIN);
+
+        assert_eq!((max + Number::from(-1)).as_u64(), Some(u64::MAX - 1));
+        assert_eq!((max - Number::from(1)).as_u64(), Some(u64::MAX - 1));
+        assert_eq!((max % Number::from(-10)).as_u64(), Some(5));
+        assert_eq!(
+            (min + Number::from(u64::MAX)).as_u64(),
+            Some(i64::MAX as u64)
+        );
+        assert_eq!((-min).as_u64(), Some(1 << 63));
+        assert_eq!(-Number::from(1u64 << 63), min);
+        assert_eq!(-Number::from(0u64), Number::from(0u64));
+        assert!((-max).is_f64());
+        assert!((max + max).is_f64());
+        assert!((max * Number::from(2)).is_f64());
+    }
+
+    #[test]
+    fn from_str() {
+        let int = "42".parse::<Number>().unwrap();
+        assert!(int.is_u64());
+        assert_eq!(int, Number::from(42u64));
+
+        let neg = "-42".parse::<Number>().unwrap();
+        assert!(neg.is_i64() && !neg.is_u64());
+        assert_eq!(neg, Number::from(-42));
+
+        let float = "42.0".parse::<Number>().unwrap();
+        assert!(float.is_f64());
+        assert_eq!(float.to_string(), "42.0");
+
+        let exp = "4.2e1".parse::<Number>().unwrap();
+        assert!(exp.is_f64());
+        assert_eq!(exp, Number::from_f64(42.0).unwrap());
+        assert!("1E-3".parse::<Number>().unwrap().is_f64());
+        assert_eq!(
+            "2.75".parse::<Number>().unwrap(),
+            Number::from_f64(2.75).unwrap()
+        );
+        assert_eq!(
+            "1e10".parse::<Number>().unwrap(),
+            Number::from_f64(1e10).unwrap()
+        );
+        assert!("-2.5E+3".parse::<Number>().unwrap().is_f64());
+
+        // Integers have no negative zero.
+        let zero = "-0".parse::<Number>().unwrap();
+        assert!(zero.is_u64());
+        assert_eq!(zero, Number::from(0u64));
+
+        assert_eq!(
+            "-9223372036854775808".parse::<Number>().unwrap(),
+            Number::from(i64::MIN)
+        );
+        assert!("18446744073709551616".parse::<Number>().unwrap().is_f64());
+
+        for invalid in [
+            "", "-", "+1", "1.", ".5", "1e", "1e+", "1e+-5", "0x10", "0x1F", "inf", "NaN", "1e400",
+        ] {
+            assert!(invalid.parse::<Number>().is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn as_128_bit() {
+        assert_eq!(Number::from(0u64).as_u128(), Some(0));
+        assert_eq!(Number::from(u64::MAX).as_u128(), Some(u128::from(u64::MAX)));
+        assert_eq!(Number::from(-1).as_u128(), None);
+        assert_eq!(Number::from(-1).as_i128(), Some(-1));
+        assert_eq!(Number::from(i64::MIN).as_i128(), Some(i128::from(i64::MIN)));
+
+        // Integers beyond `u64::MAX` are stored as floats.
+        let large: Number = "18446744073709551616".parse().unwrap();
+        assert_eq!(large.as_u128(), None);
+        assert_eq!(large.as_i128(), None);
+        assert_eq!(
+            (Number::from(u64::MAX) + Number::from(1u64)).as_u128(),
+            None
+        );
+        assert_eq!(Number::from_f64(2.0).unwrap().as_i128(), None);
+    }
+
+    #[test]
+    fn neg_large_unsigned() {
+        let neg = -Number::from(u64::MAX);
+        let f = neg.as_f64().unwrap();
+        assert!(neg.is_f64());
+        assert!(f.is_finite() && f < 0.0);
+        assert_eq!(neg, Number::from_f64(-(u64::MAX as f64)).unwrap());
+
+        let neg = -Number::from(i64::MAX as u64 + 2);
+        assert!(neg.is_f64());
+        assert!(neg.as_f64().unwrap() < 0.0);
+        assert_eq!(-Number::from(i64::MAX as u64 + 1), Number::from(i64::MIN));
+    }
+
+    #[test]
+    fn checked_ops() {
+        let max = Number::from(u64::MAX);
+        let min = Number::from(i64::MIN);
+        let one = Number::from(1u64);
+        let zero = Number::from(0u64);
+        let huge = Number::from_f64(f64::MAX).unwrap();
+
+        let sum = max.checked_add(one).unwrap();
+        assert!(sum.is_f64());
+        assert_eq!(sum, Number::from_f64(u64::MAX as f64 + 1.0).unwrap());
+        assert!(max.checked_add(Number::from(-1)).unwrap().is_u64());
+        assert_eq!(huge.checked_add(huge), None);
+
+        let negated = zero.checked_sub(min).unwrap();
+        assert!(negated.is_u64());
+        assert_eq!(negated.as_u64(), Some(1 << 63));
+        assert!(min.checked_sub(one).unwrap().is_f64());
+        assert_eq!(
+            Number::from(-1).checked_sub(Number::from(2u64)),
+            Some(Number::from(-3))
+        );
+
+        let product = min.checked_mul(max).unwrap();
+        assert!(product.is_f64());
+        assert!(product.as_f64().unwrap() < 0.0);
+        assert!(Number::from(-3)
+            .checked_mul(Number::from(u64::MAX))
+            .unwrap()
+            .is_f64());
+        assert_eq!(
+            Number::from(-3).checked_mul(Number::from(4u64)),
+            Some(Number::from(-12))
+        );
+        assert_eq!(huge.checked_mul(Number::from(2u64)), None);
+
+        assert_eq!(
+            min.checked_div(Number::from(-1)),
+            Some(Number::from(1u64 << 63))
+        );
+        assert!(Number::from(7u64)
+            .checked_div(Number::from(2u64))
+            .unwrap()
+            .is_f64());
+        assert_eq!(one.checked_div(zero), None);
+        assert_eq!(Number::from_f64(1.5).unwrap().checked_div(zero), None);
+        assert_eq!(one.checked_div(Number::from_f64(0.0).unwrap()), None);
+
+        assert_eq!(
+            Number::from(7u64).checked_rem(Number::from(-2)),
+            Some(Number::from(1u64))
+        );
+        assert_eq!(one.checked_rem(zero), None);
+        assert_eq!(one.checked_rem(Number::from_f64(0.0).unwrap()), None);
+    }
+
+    #[test]
+    fn display_decimal_point() {
+        let tests = [
+            (1.5, "1.5"),
+            (-0.25, "-0.25"),
+            (1_234_567.125, "1234567.125"),
+            (1.5e-7, "1.5e-7"),
+            (1e21, "1e21"),
+        ];
+
+        for (f, expected) in tests {
+            let number = Number::from_f64(f).unwrap();
+            assert_eq!(number.to_string(), expected);
+            assert_eq!(
+                crate::to_string(&crate::value!({ n = (number) })).unwrap(),
+                format!("n = {expected}\n")
+            );
+            assert_eq!(
+                crate::from_str::<crate::Value>(&format!("n = {expected}")).unwrap(),
+                crate::value!({ n = (number) })
+            );
+        }
+    }
+
+    #[test]
+    fn display_large_integers() {
+        let tests = [
+            (Number::from(u64::MAX), "18446744073709551615"),
+            (Number::from(i64::MIN), "-9223372036854775808"),
+            (Number::from(1u64 << 53), "9007199254740992"),
+            (Number::from((1u64 << 53) + 1), "9007199254740993"),
+            (Number::from(-(1i64 << 53) - 1), "-9007199254740993"),
+        ];
+
+        for (number, expected) in tests {
+            assert_eq!(number.to_string(), expected);
+            assert_eq!(crate::Value::Number(number).to_string(), expected);
+            assert_eq!(
+                crate::to_string(&crate::value!({ n = (number) })).unwrap(),
+                format!("n = {expected}\n")
+            );
+        }
+    }
+
+    #[test]
+    fn from_f32() {
+        for (f, expected) in [
+            (0.1f32, "0.1"),
+            (-2.5e-3, "-0.0025"),
+            (16_777_216.0, "16777216.0"),
+            (f32::MAX, "3.4028235e38"),
+            (f32::MIN_POSITIVE, "1.1754944e-38"),
+        ] {
+            let num = Number::from_f32(f).unwrap();
+            assert_eq!(num.to_string(), expected);
+            assert_eq!((num.as_f64().unwrap() as f32).to_bits(), f.to_bits());
+        }
+
+        assert!(Number::from_f32(f32::INFINITY).is_none());
+    }
+
+    #[test]
+    fn ordering() {
+        let int = Number::from(1u64 << 53);
+        let int_plus_one = Number::from((1u64 << 53) + 1);
+        let float = Number::from_f64((1u64 << 53) as f64).unwrap();
+
+        // Integers which are not exactly representable as `f64` do not compare equal to floats.
+        assert_eq!(int, float);
+        assert!(float < int_plus_one);
+        assert!(int < int_plus_one);
+
+        // `u64::MAX` rounds up to 2^64 when converted to `f64`.
+        let two_pow_64 = Number::from_f64(1.844_674_407_370_955_2e19).unwrap();
+        assert_ne!(Number::from(u64::MAX), two_pow_64);
+        assert!(Number::from(u64::MAX) < two_pow_64);
+
+        assert!(Number::from(u64::MAX) < Number::from_f64(1.9e19).unwrap());
+        assert!(Number::from(i64::MIN) > Number::from_f64(-1e19).unwrap());
+        assert!(Number::from(-2) < Number::from_f64(-1.5).unwrap());
+        assert!(Number::from(-1) > Number::from_f64(-1.5).unwrap());
+        assert!(Number::from(1u64) < Number::from_f64(1.5).unwrap());
+        assert_eq!(Number::from(0u64), Number::from_f64(-0.0).unwrap());
+    }
+
+    #[test]
+    fn min_max_clamp() {
+        let int = Number::from(1u64);
+        let neg = Number::from(-2);
+        let float = Number::from_f64(1.5).unwrap();
+        let int_float = Number::from_f64(1.0).unwrap();
+
+        assert!(int.min(float).is_u64());
+        assert_eq!(int.min(float), int);
+        assert_eq!(float.min(neg), neg);
+        assert!(int.max(float).is_f64());
+        assert_eq!(neg.max(int), int);
+
+        // Equal numbers of different variants.
+        assert!(int.min(int_float).is_u64());
+        assert!(int.max(int_float).is_f64());
+
+        assert_eq!(float.clamp(neg, int), int);
+        assert_eq!(neg.clamp(int, float), int);
+        assert!(int.clamp(neg, float).is_u64());
+    }
 }
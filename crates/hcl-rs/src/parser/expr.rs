@@ -73,10 +73,12 @@ fn expr_term(pair: Pair<Rule>) -> Result<Expression> {
 
     let expr = match pair.as_rule() {
         Rule::BooleanLit => Expression::Bool(from_str(pair)),
-        Rule::Float => {
-            Number::from_f64(from_str::<f64>(pair)).map_or(Expression::Null, Expression::Number)
-        }
-        Rule::Int => Expression::from(from_str::<i64>(pair)),
+        // A decimal point or exponent always yields a float, e.g. `42.0` is not collapsed into an
+        // integer. Floats that overflow to infinity are represented as `null`.
+        Rule::Float | Rule::Int => pair
+            .as_str()
+            .parse::<Number>()
+            .map_or(Expression::Null, Expression::Number),
         Rule::NullLit => Expression::Null,
         Rule::StringLit => unescape_string(inner(pair)).map(Expression::String)?,
         Rule::TemplateExpr => Expression::TemplateExpr(Box::new(template_expr(inner(pair)))),
@@ -240,6 +242,8 @@ fn object_key(pair: Pair<Rule>) -> Result<ObjectKey> {
 }
 
 fn heredoc(pair: Pair<Rule>) -> Heredoc {
+    let heredoc_start = pair.as_span().start();
+    let heredoc_str = pair.as_str();
     let mut pairs = pair.into_inner();
     let intro = pairs.next().unwrap();
 
@@ -253,13 +257,20 @@ fn heredoc(pair: Pair<Rule>) -> Heredoc {
 
     let template = pairs.next().unwrap();
 
+    // The newline which terminates the last line of the template is consumed by the grammar.
+    let newline = if heredoc_str[template.as_span().end() - heredoc_start..].starts_with("\r\n") {
+        "\r\n"
+    } else {
+        "\n"
+    };
+
     let mut template = match strip {
         HeredocStripMode::None => string(template),
         HeredocStripMode::Indent => dedent(template.as_str()).to_string(),
     };
 
     // Append the trailing newline here. This is easier than doing this in the grammar.
-    template.push('\n');
+    template.push_str(newline);
 
     Heredoc {
         delimiter,
@@ -6,14 +6,15 @@ mod tests;
 
 use self::{expr::expression, structure::body, template::template};
 use crate::{
-    expr::Expression, structure::Body, template::Template, util::unescape, Identifier, Number,
-    Result,
+    error::Location, expr::Expression, structure::Body, template::Template, util::unescape, Error,
+    Identifier, Number, Result,
 };
 use pest::{
     iterators::{Pair, Pairs},
-    Parser as _,
+    Parser as _, Span,
 };
 use pest_derive::Parser;
+use std::borrow::Cow;
 use std::str::FromStr;
 
 #[derive(Parser)]
@@ -22,6 +23,11 @@ struct HclParser;
 
 /// Parse a `hcl::Body` from a `&str`.
 ///
+/// The returned [`Body`] is a structural representation of the input (comments and formatting
+/// are not preserved): expressions are retained as-is and blocks are not merged into objects like
+/// they are when deserializing into a [`Value`][crate::Value]. This is equivalent to
+/// `hcl::from_str::<Body>(input)` and `input.parse::<Body>()`.
+///
 /// If deserialization into a different type is preferred consider using [`hcl::from_str`][from_str].
 ///
 /// [from_str]: ./de/fn.from_str.html
@@ -62,8 +68,391 @@ struct HclParser;
 ///
 /// This function fails with an error if the `input` cannot be parsed as HCL.
 pub fn parse(input: &str) -> Result<Body> {
-    let pair = HclParser::parse(Rule::Hcl, input)?.next().unwrap();
-    body(pair)
+    parse_with_options(input, &ParseOptions::default())
+}
+
+/// Options to control the behaviour of the HCL parser.
+///
+/// See [`parse_with_options`] for an example.
+#[derive(Debug, Default, Clone)]
+pub struct ParseOptions {
+    normalize_newlines: bool,
+    max_structures: Option<usize>,
+    bare_strings: bool,
+    raw_expressions: bool,
+    literals_only: bool,
+    preserve_comments: bool,
+    preserve_spans: bool,
+    unescape_template_sequences: bool,
+}
+
+impl ParseOptions {
+    /// Creates a new `ParseOptions` with all options disabled.
+    pub fn new() -> ParseOptions {
+        ParseOptions::default()
+    }
+
+    /// If enabled, Windows-style line endings (`\r\n`) are normalized to `\n` before parsing.
+    ///
+    /// Outside of heredocs line endings are not significant, so this effectively ensures that
+    /// heredoc templates only contain `\n` line endings regardless of the line ending style of
+    /// the input. By default the original line endings are retained.
+    pub fn normalize_newlines(mut self, yes: bool) -> ParseOptions {
+        self.normalize_newlines = yes;
+        self
+    }
+
+    /// Sets the maximum number of structures (attributes and blocks, including nested ones) that
+    /// the input may contain. Parsing fails with an error if the limit is exceeded.
+    ///
+    /// This can be used to limit the size of the resulting [`Body`] when parsing untrusted input.
+    /// The limit is checked after the input was parsed syntactically, but before any structure
+    /// is converted into its [`Body`] representation. It therefore does not limit the cost of the
+    /// syntactic parsing itself, which grows with the length of the input. Limit the input
+    /// length before parsing to bound that as well. By default the number of structures is
+    /// unlimited.
+    pub fn max_structures(mut self, max: usize) -> ParseOptions {
+        self.max_structures = Some(max);
+        self
+    }
+
+    /// If enabled, unquoted attribute values consisting of a single identifier are parsed as
+    /// string literals instead of variables.
+    ///
+    /// This is non-standard behaviour which allows parsing HCL-like input such as
+    /// `region = us-east-1`. Keywords (`true`, `false` and `null`), numbers and all other kinds of
+    /// expressions (e.g. traversals like `var.region`) are not affected. The option only applies
+    /// to attribute values, not to nested expressions like array elements. Disabled by default.
+    pub fn bare_strings(mut self, yes: bool) -> ParseOptions {
+        self.bare_strings = yes;
+        self
+    }
+
+    /// If enabled, the value expressions of attributes are not parsed into an expression tree
+    /// but are retained verbatim as [`Expression::Raw`][crate::Expression::Raw] holding the
+    /// original source text.
+    ///
+    /// The formatter emits raw expressions as-is, so this can be used to fix indentation and
+    /// structure layout of a document without normalizing expressions (e.g. `a+b` is not
+    /// reformatted to `a + b`). The input must still be syntactically valid HCL. Since raw
+    /// expressions are opaque, they cannot be evaluated or deserialized into structured data.
+    /// Disabled by default.
+    ///
+    /// This option takes precedence over options which modify attribute values, so
+    /// [`bare_strings`][ParseOptions::bare_strings] and
+    /// [`unescape_template_sequences`][ParseOptions::unescape_template_sequences] have no effect
+    /// if it is enabled. [`literals_only`][ParseOptions::literals_only] is still enforced since
+    /// it is checked before any value is converted.
+    ///
+    /// ```
+    /// use hcl::ParseOptions;
+    ///
+    /// let input = "foo = a+b\nblock {\nbar = [1,2]\n}\n";
+    ///
+    /// let options = ParseOptions::new().raw_expressions(true);
+    /// let body = hcl::parse_with_options(input, &options)?;
+    ///
+    /// assert_eq!(
+    ///     hcl::format::to_string(&body)?,
+    ///     "foo = a+b\n\nblock {\n  bar = [1,2]\n}\n"
+    /// );
+    /// # Ok::<(), hcl::Error>(())
+    /// ```
+    pub fn raw_expressions(mut self, yes: bool) -> ParseOptions {
+        self.raw_expressions = yes;
+        self
+    }
+
+    /// If enabled, parsing fails with an error if an attribute value contains anything other
+    /// than literal values.
+    ///
+    /// Literal values are strings, numbers (including negative numbers), booleans, `null`,
+    /// heredocs without interpolations or directives, and arrays and objects consisting of
+    /// literal values. Variables, traversals, function calls, operations, conditionals, `for`
+    /// expressions and templates that contain interpolations or directives are rejected. This
+    /// guarantees that the parsed body is self-contained and does not reference any external
+    /// state. The error contains the location of the first offending expression. Disabled by
+    /// default.
+    ///
+    /// ```
+    /// use hcl::ParseOptions;
+    ///
+    /// let options = ParseOptions::new().literals_only(true);
+    ///
+    /// assert!(hcl::parse_with_options("x = [1, \"two\", { three = 3 }]", &options).is_ok());
+    ///
+    /// let err = hcl::parse_with_options("x = var.y", &options).unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "traversal is not allowed in literals-only mode in line 1, col 5"
+    /// );
+    /// ```
+    pub fn literals_only(mut self, yes: bool) -> ParseOptions {
+        self.literals_only = yes;
+        self
+    }
+
+    /// If enabled, line comments directly preceding an attribute or block are attached to it as
+    /// its `comment`, so that they are retained when the body is formatted again.
+    ///
+    /// The association is best-effort: only consecutive `#` and `//` line comments on the lines
+    /// immediately above a structure are attached, a blank line ends the comment. The comment
+    /// markers and a single following space are stripped, so `//` comments are written as `#`
+    /// comments by the formatter. Trailing comments, block comments (`/* */`) and comments within
+    /// expressions are always discarded, even if this option is enabled. Disabled by default.
+    ///
+    /// Attached comments take part in equality comparisons, so a body parsed with this option
+    /// enabled does not compare equal to the same input parsed without it if any comment was
+    /// attached. Use [`Body::semantic_eq`][crate::Body::semantic_eq] to compare bodies while
+    /// ignoring comments.
+    ///
+    /// ```
+    /// use hcl::ParseOptions;
+    ///
+    /// let input = "# primary region\nregion = \"us-east-1\"\n";
+    ///
+    /// let options = ParseOptions::new().preserve_comments(true);
+    /// let mut body = hcl::parse_with_options(input, &options)?;
+    ///
+    /// body.get_attribute_mut("region").unwrap().expr = "eu-west-1".into();
+    ///
+    /// assert_eq!(
+    ///     hcl::to_string(&body)?,
+    ///     "# primary region\nregion = \"eu-west-1\"\n"
+    /// );
+    /// # Ok::<(), hcl::Error>(())
+    /// ```
+    pub fn preserve_comments(mut self, yes: bool) -> ParseOptions {
+        self.preserve_comments = yes;
+        self
+    }
+
+    /// If enabled, the byte range of each attribute and block within the input is recorded and
+    /// can be obtained via [`Attribute::span`][crate::Attribute::span] and
+    /// [`Block::span`][crate::Block::span].
+    ///
+    /// Spans refer to the input after newline normalization (see
+    /// [`normalize_newlines`][ParseOptions::normalize_newlines]). They can be used to re-emit the
+    /// original source of structures that were not modified after parsing, since modifying a
+    /// structure does not update its span. Spans are ignored when comparing structures for
+    /// equality, so enabling this option does not affect comparisons with bodies that were parsed
+    /// without it or built in code. Disabled by default.
+    ///
+    /// ```
+    /// use hcl::ParseOptions;
+    ///
+    /// let input = "region   =   \"us-east-1\" # primary\n";
+    ///
+    /// let options = ParseOptions::new().preserve_spans(true);
+    /// let body = hcl::parse_with_options(input, &options)?;
+    ///
+    /// let span = body.get_attribute("region").unwrap().span().unwrap();
+    ///
+    /// assert_eq!(&input[span], "region   =   \"us-east-1\"");
+    /// # Ok::<(), hcl::Error>(())
+    /// ```
+    pub fn preserve_spans(mut self, yes: bool) -> ParseOptions {
+        self.preserve_spans = yes;
+        self
+    }
+
+    /// If enabled, escaped template sequence introductions (`$${` and `%%{`) in string literals
+    /// and block labels are decoded to a literal `${` and `%{`.
+    ///
+    /// By default, string literals retain the escaped form, so that they are written unchanged
+    /// by the formatter. Enable this together with
+    /// [`FormatterBuilder::escape_template_sequences`][escape] to treat strings as literal
+    /// values which round-trip through parsing and formatting. Template expressions are not
+    /// affected. Disabled by default.
+    ///
+    /// ```
+    /// use hcl::{Body, ParseOptions};
+    ///
+    /// let input = r#"literal = "$${x}""#;
+    ///
+    /// let options = ParseOptions::new().unescape_template_sequences(true);
+    /// let body = hcl::parse_with_options(input, &options)?;
+    ///
+    /// assert_eq!(body, Body::builder().add_attribute(("literal", "${x}")).build());
+    /// # Ok::<(), hcl::Error>(())
+    /// ```
+    ///
+    /// [escape]: crate::format::FormatterBuilder::escape_template_sequences
+    pub fn unescape_template_sequences(mut self, yes: bool) -> ParseOptions {
+        self.unescape_template_sequences = yes;
+        self
+    }
+}
+
+/// Parse a `hcl::Body` from a `&str` using the provided [`ParseOptions`].
+///
+/// # Example
+///
+/// ```
+/// use hcl::expr::{Heredoc, TemplateExpr};
+/// use hcl::{Body, ParseOptions};
+/// # use std::error::Error;
+/// #
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// let input = "heredoc = <<EOS\r\nfoo\r\nbar\r\nEOS\r\n";
+///
+/// let options = ParseOptions::new().normalize_newlines(true);
+/// let body = hcl::parse_with_options(input, &options)?;
+///
+/// let expected = Body::builder()
+///     .add_attribute((
+///         "heredoc",
+///         TemplateExpr::Heredoc(Heredoc::new("EOS".into(), "foo\nbar\n")),
+///     ))
+///     .build();
+///
+/// assert_eq!(body, expected);
+/// #   Ok(())
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// This function fails with an error if the `input` cannot be parsed as HCL or if it violates any
+/// of the limits configured in `options`.
+pub fn parse_with_options(input: &str, options: &ParseOptions) -> Result<Body> {
+    let input = if options.normalize_newlines && input.contains("\r\n") {
+        Cow::Owned(input.replace("\r\n", "\n"))
+    } else {
+        Cow::Borrowed(input)
+    };
+
+    let pair = HclParser::parse(Rule::Hcl, &input)?.next().unwrap();
+
+    if let Some(max) = options.max_structures {
+        check_max_structures(&pair, max)?;
+    }
+
+    if options.literals_only {
+        check_literals_only(&pair)?;
+    }
+
+    body(pair, options)
+}
+
+// Counts the structures in the parsed body before any of them is converted into its `Structure`
+// representation.
+fn check_max_structures(pair: &Pair<Rule>, max: usize) -> Result<()> {
+    let mut structures = pair
+        .clone()
+        .into_inner()
+        .flatten()
+        .filter(|pair| matches!(pair.as_rule(), Rule::Attribute | Rule::Block));
+
+    match structures.nth(max) {
+        Some(pair) => Err(Error::Message {
+            msg: format!("number of structures exceeds the maximum of {max}"),
+            location: Some(Location::from(pair.as_span())),
+        }),
+        None => Ok(()),
+    }
+}
+
+// Ensures that all attribute values only consist of literal values before any of them is
+// converted into an `Expression`.
+fn check_literals_only(pair: &Pair<Rule>) -> Result<()> {
+    pair.clone()
+        .into_inner()
+        .flatten()
+        .filter(|pair| pair.as_rule() == Rule::Attribute)
+        .try_for_each(|pair| check_literal_expr(pair.into_inner().nth(1).unwrap()))
+}
+
+fn check_literal_expr(pair: Pair<Rule>) -> Result<()> {
+    let span = pair.as_span();
+    let pairs: Vec<_> = pair.into_inner().collect();
+
+    match pairs.as_slice() {
+        [term] => check_literal_term(term.clone()),
+        // Negative numbers are parsed as numeric literals.
+        [op, term] if op.as_str() == "-" && is_numeric_term(term) => Ok(()),
+        [op, ..] if op.as_rule() == Rule::UnaryOperator => non_literal("operation", span),
+        [_, op, ..] if op.as_rule() == Rule::BinaryOperator => non_literal("operation", span),
+        _ => non_literal("conditional", span),
+    }
+}
+
+fn is_numeric_term(pair: &Pair<Rule>) -> bool {
+    let mut pairs = pair.clone().into_inner();
+
+    matches!(
+        pairs.next().map(|pair| pair.as_rule()),
+        Some(Rule::Int | Rule::Float)
+    ) && pairs.next().is_none()
+}
+
+fn check_literal_term(pair: Pair<Rule>) -> Result<()> {
+    let span = pair.as_span();
+    let mut pairs = pair.into_inner();
+    let pair = pairs.next().unwrap();
+
+    if pairs.next().is_some() {
+        return non_literal("traversal", span);
+    }
+
+    match pair.as_rule() {
+        Rule::BooleanLit | Rule::Float | Rule::Int | Rule::NullLit | Rule::StringLit => Ok(()),
+        Rule::Tuple => pair.into_inner().try_for_each(check_literal_expr),
+        Rule::Object => pair
+            .into_inner()
+            .filter(|pair| pair.as_rule() == Rule::Expression)
+            .try_for_each(check_literal_expr),
+        Rule::TemplateExpr => {
+            match pair.into_inner().flatten().find(|pair| {
+                matches!(
+                    pair.as_rule(),
+                    Rule::TemplateInterpolation | Rule::TemplateDirective
+                )
+            }) {
+                Some(pair) if pair.as_rule() == Rule::TemplateInterpolation => {
+                    non_literal("template interpolation", pair.as_span())
+                }
+                Some(pair) => non_literal("template directive", pair.as_span()),
+                None => Ok(()),
+            }
+        }
+        Rule::FunctionCall => non_literal("function call", span),
+        Rule::Variable => non_literal("variable", span),
+        Rule::ForExpr => non_literal("for expression", span),
+        Rule::Parenthesis => non_literal("parenthesized expression", span),
+        rule => unexpected_rule(rule),
+    }
+}
+
+fn non_literal(kind: &str, span: Span<'_>) -> Result<()> {
+    Err(Error::Message {
+        msg: format!("{kind} is not allowed in literals-only mode"),
+        location: Some(Location::from(span)),
+    })
+}
+
+// Splits off the first document from a stream of documents separated by `---` lines. Returns the
+// input following the separator line, or `None` if `input` does not contain another separator.
+//
+// The input is parsed up to the end of the document's body first, so that `---` lines within
+// heredocs or within block comments preceding a structure do not end the document. If the body
+// contains a syntax error, the separator search starts at the line of the error.
+pub(crate) fn split_document(input: &str) -> (&str, Option<&str>) {
+    let body_end = HclParser::parse(Rule::Document, input)
+        .ok()
+        .and_then(|mut pairs| pairs.next())
+        .map_or(0, |pair| pair.as_span().end());
+    let mut offset = input[..body_end].rfind('\n').map_or(0, |pos| pos + 1);
+
+    for line in input[offset..].split_inclusive('\n') {
+        if line.trim_end() == "---" {
+            return (&input[..offset], Some(&input[offset + line.len()..]));
+        }
+
+        offset += line.len();
+    }
+
+    (input, None)
 }
 
 pub fn parse_template(input: &str) -> Result<Template> {
@@ -1,28 +1,192 @@
 use super::*;
+use crate::expr::{ObjectKey, Operation, RawExpression, TraversalOperator};
 use crate::structure::{Attribute, Block, BlockLabel, Body, Structure};
+use std::ops::Range;
 
-pub fn body(pair: Pair<Rule>) -> Result<Body> {
-    pair.into_inner().map(structure).collect()
+pub fn body(pair: Pair<Rule>, options: &ParseOptions) -> Result<Body> {
+    pair.into_inner()
+        .map(|pair| structure(pair, options))
+        .collect()
 }
 
-fn structure(pair: Pair<Rule>) -> Result<Structure> {
+fn structure(pair: Pair<Rule>, options: &ParseOptions) -> Result<Structure> {
+    let comment = if options.preserve_comments {
+        leading_comment(pair.as_span())
+    } else {
+        None
+    };
+
+    let span = if options.preserve_spans {
+        Some(structure_span(pair.clone()))
+    } else {
+        None
+    };
+
     match pair.as_rule() {
-        Rule::Attribute => attribute(pair).map(Structure::Attribute),
-        Rule::Block => block(pair).map(Structure::Block),
+        Rule::Attribute => attribute(pair, options).map(|attr| {
+            Structure::Attribute(Attribute {
+                comment,
+                span,
+                ..attr
+            })
+        }),
+        Rule::Block => block(pair, options).map(|block| {
+            Structure::Block(Block {
+                comment,
+                span,
+                ..block
+            })
+        }),
         rule => unexpected_rule(rule),
     }
 }
 
-fn attribute(pair: Pair<Rule>) -> Result<Attribute> {
+// Returns the byte range of an attribute or block. Trailing whitespace and comments which were
+// consumed by the attribute's expression are excluded.
+fn structure_span(pair: Pair<Rule>) -> Range<usize> {
+    let start = pair.as_span().start();
+    let end = match pair.as_rule() {
+        Rule::Attribute => expression_end(pair.into_inner().last().unwrap()),
+        _ => pair.as_span().end(),
+    };
+
+    start..end
+}
+
+// Collects the consecutive line comments on the lines immediately above `span`. Returns `None`
+// if there are none or if `span` does not start on its own line.
+fn leading_comment(span: Span<'_>) -> Option<String> {
+    let preceding = &span.get_input()[..span.start()];
+    let (preceding, indent) = preceding.rsplit_once('\n')?;
+
+    if !indent.trim().is_empty() {
+        return None;
+    }
+
+    let mut lines: Vec<&str> = preceding
+        .lines()
+        .rev()
+        .map(str::trim)
+        .map_while(|line| line.strip_prefix('#').or_else(|| line.strip_prefix("//")))
+        .map(|line| line.strip_prefix(' ').unwrap_or(line))
+        .collect();
+
+    if lines.is_empty() {
+        return None;
+    }
+
+    lines.reverse();
+    Some(lines.join("\n"))
+}
+
+fn attribute(pair: Pair<Rule>, options: &ParseOptions) -> Result<Attribute> {
     let mut pairs = pair.into_inner();
+    let key = ident(pairs.next().unwrap());
 
-    Ok(Attribute {
-        key: ident(pairs.next().unwrap()),
-        expr: expression(pairs.next().unwrap())?,
-    })
+    let pair = pairs.next().unwrap();
+
+    if options.raw_expressions {
+        let len = expression_end(pair.clone()) - pair.as_span().start();
+        let expr = Expression::Raw(RawExpression::new(&pair.as_str()[..len]));
+        return Ok(Attribute::new(key, expr));
+    }
+
+    let mut expr = match expression(pair)? {
+        Expression::Variable(var) if options.bare_strings => {
+            Expression::String(var.into_inner().into_inner())
+        }
+        expr => expr,
+    };
+
+    if options.unescape_template_sequences {
+        unescape_expr_template_sequences(&mut expr);
+    }
+
+    Ok(Attribute::new(key, expr))
 }
 
-fn block(pair: Pair<Rule>) -> Result<Block> {
+// Decodes escaped template sequence introductions in all string literals within `expr`. Template
+// expressions are not modified since the escape sequences are part of their raw template string.
+fn unescape_expr_template_sequences(expr: &mut Expression) {
+    match expr {
+        Expression::String(s) => unescape_template_sequences(s),
+        Expression::Array(array) => array.iter_mut().for_each(unescape_expr_template_sequences),
+        Expression::Object(object) => {
+            *object = std::mem::take(object)
+                .into_iter()
+                .map(|(mut key, mut value)| {
+                    if let ObjectKey::Expression(expr) = &mut key {
+                        unescape_expr_template_sequences(expr);
+                    }
+                    unescape_expr_template_sequences(&mut value);
+                    (key, value)
+                })
+                .collect();
+        }
+        Expression::Traversal(traversal) => {
+            unescape_expr_template_sequences(&mut traversal.expr);
+
+            for operator in &mut traversal.operators {
+                if let TraversalOperator::Index(expr) = operator {
+                    unescape_expr_template_sequences(expr);
+                }
+            }
+        }
+        Expression::FuncCall(func_call) => func_call
+            .args
+            .iter_mut()
+            .for_each(unescape_expr_template_sequences),
+        Expression::Parenthesis(expr) => unescape_expr_template_sequences(expr),
+        Expression::Conditional(cond) => {
+            unescape_expr_template_sequences(&mut cond.cond_expr);
+            unescape_expr_template_sequences(&mut cond.true_expr);
+            unescape_expr_template_sequences(&mut cond.false_expr);
+        }
+        Expression::Operation(op) => match op.as_mut() {
+            Operation::Unary(op) => unescape_expr_template_sequences(&mut op.expr),
+            Operation::Binary(op) => {
+                unescape_expr_template_sequences(&mut op.lhs_expr);
+                unescape_expr_template_sequences(&mut op.rhs_expr);
+            }
+        },
+        Expression::ForExpr(for_expr) => {
+            unescape_expr_template_sequences(&mut for_expr.collection_expr);
+            unescape_expr_template_sequences(&mut for_expr.value_expr);
+
+            if let Some(key_expr) = &mut for_expr.key_expr {
+                unescape_expr_template_sequences(key_expr);
+            }
+
+            if let Some(cond_expr) = &mut for_expr.cond_expr {
+                unescape_expr_template_sequences(cond_expr);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn unescape_template_sequences(s: &mut String) {
+    if s.contains("$${") || s.contains("%%{") {
+        *s = s.replace("$${", "${").replace("%%{", "%{");
+    }
+}
+
+// Returns the end offset of an expression excluding trailing whitespace and comments.
+//
+// The span of an `Expression` or `ExprTerm` pair may include implicit whitespace and comments
+// that were consumed while trying to match the optional trailing parts of the rule, so the end of
+// the last inner pair is used instead.
+fn expression_end(pair: Pair<Rule>) -> usize {
+    match pair.as_rule() {
+        Rule::Expression | Rule::ExprTerm => {
+            let end = pair.as_span().end();
+            pair.into_inner().last().map_or(end, expression_end)
+        }
+        _ => pair.as_span().end(),
+    }
+}
+
+fn block(pair: Pair<Rule>, options: &ParseOptions) -> Result<Block> {
     let mut pairs = pair.into_inner();
     let identifier = ident(pairs.next().unwrap());
     let (labels, body): (Vec<Pair<Rule>>, Vec<Pair<Rule>>) =
@@ -30,22 +194,35 @@ fn block(pair: Pair<Rule>) -> Result<Block> {
 
     Ok(Block {
         identifier,
-        labels: labels.into_iter().map(block_label).collect::<Result<_>>()?,
-        body: block_body(body.into_iter().next().unwrap())?,
+        labels: labels
+            .into_iter()
+            .map(|pair| block_label(pair, options))
+            .collect::<Result<_>>()?,
+        body: block_body(body.into_iter().next().unwrap(), options)?,
+        comment: None,
+        span: None,
     })
 }
 
-fn block_label(pair: Pair<Rule>) -> Result<BlockLabel> {
+fn block_label(pair: Pair<Rule>, options: &ParseOptions) -> Result<BlockLabel> {
     match pair.as_rule() {
         Rule::Identifier => Ok(BlockLabel::Identifier(ident(pair))),
-        Rule::StringLit => unescape_string(inner(pair)).map(BlockLabel::String),
+        Rule::StringLit => {
+            let mut label = unescape_string(inner(pair))?;
+
+            if options.unescape_template_sequences {
+                unescape_template_sequences(&mut label);
+            }
+
+            Ok(BlockLabel::String(label))
+        }
         rule => unexpected_rule(rule),
     }
 }
 
-fn block_body(pair: Pair<Rule>) -> Result<Body> {
+fn block_body(pair: Pair<Rule>, options: &ParseOptions) -> Result<Body> {
     match pair.as_rule() {
-        Rule::BlockBody => body(inner(pair)),
+        Rule::BlockBody => body(inner(pair), options),
         rule => unexpected_rule(rule),
     }
 }
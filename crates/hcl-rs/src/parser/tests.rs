@@ -1,4 +1,8 @@
 use super::*;
+use crate::error::Location;
+use crate::expr::{Heredoc, HeredocStripMode, ObjectKey, RawExpression, TemplateExpr};
+use crate::structure::{Attribute, Block, Structure};
+use indoc::indoc;
 use pest::*;
 
 #[test]
@@ -376,3 +380,638 @@ fn parse_escaped_slash_in_string() {
         ]
     };
 }
+
+#[test]
+fn parse_object_value_separators() {
+    let body = parse("object = { a = 1, b : 2 }").unwrap();
+
+    let expected = Body::from(Attribute::new(
+        "object",
+        Expression::from_iter([
+            (ObjectKey::Identifier(Identifier::unchecked("a")), 1),
+            (ObjectKey::Identifier(Identifier::unchecked("b")), 2),
+        ]),
+    ));
+
+    assert_eq!(body, expected);
+
+    // `:` separators are normalized to `=`.
+    assert_eq!(
+        crate::format::to_string(&body).unwrap(),
+        "object = {\n  a = 1\n  b = 2\n}\n"
+    );
+}
+
+#[test]
+fn parse_indented_heredoc() {
+    let input = indoc! {r#"
+        resource "aws_instance" "web" {
+          user_data = <<-EOT
+            #!/bin/sh
+
+              echo "hello"
+            done
+            EOT
+        }
+    "#};
+
+    let body = parse(input).unwrap();
+    let expected_template = "#!/bin/sh\n\n  echo \"hello\"\ndone\n";
+
+    let heredoc = body
+        .get_block("resource", &["aws_instance", "web"])
+        .unwrap()
+        .body()
+        .get_attribute("user_data")
+        .unwrap()
+        .expr();
+
+    assert_eq!(
+        heredoc,
+        &Expression::from(TemplateExpr::Heredoc(
+            Heredoc::new(Identifier::unchecked("EOT"), expected_template)
+                .with_strip_mode(HeredocStripMode::Indent)
+        ))
+    );
+
+    let value: crate::Value = crate::from_str(input).unwrap();
+    assert_eq!(
+        value.pointer("/resource/aws_instance/web/user_data"),
+        Some(&crate::Value::from(expected_template))
+    );
+}
+
+#[test]
+fn parse_crlf_heredoc() {
+    let input =
+        "a = <<EOS\r\nfoo\r\n  bar\r\nEOS\r\nb = <<-EOS\r\n    foo\r\n      bar\r\n  EOS\r\n";
+
+    let heredoc = |template: &str, strip| {
+        Expression::from(TemplateExpr::Heredoc(
+            Heredoc::new(Identifier::unchecked("EOS"), template).with_strip_mode(strip),
+        ))
+    };
+
+    // Original line endings are retained by default.
+    let body = parse(input).unwrap();
+    let expected = Body::builder()
+        .add_attribute(("a", heredoc("foo\r\n  bar\r\n", HeredocStripMode::None)))
+        .add_attribute(("b", heredoc("foo\r\n  bar\r\n", HeredocStripMode::Indent)))
+        .build();
+    assert_eq!(body, expected);
+
+    let options = ParseOptions::new().normalize_newlines(true);
+    let body = parse_with_options(input, &options).unwrap();
+    let expected = Body::builder()
+        .add_attribute(("a", heredoc("foo\n  bar\n", HeredocStripMode::None)))
+        .add_attribute(("b", heredoc("foo\n  bar\n", HeredocStripMode::Indent)))
+        .build();
+    assert_eq!(body, expected);
+
+    let formatted = crate::format::to_string(&body).unwrap();
+    assert_eq!(
+        formatted,
+        "a = <<EOS\nfoo\n  bar\nEOS\nb = <<-EOS\n  foo\n    bar\nEOS\n"
+    );
+    assert_eq!(parse_with_options(&formatted, &options).unwrap(), body);
+}
+
+#[test]
+fn parse_attribute_object_and_block() {
+    let input = "tags = {\n  env = \"prod\"\n}\n\ntags {\n  env = \"prod\"\n}\n";
+
+    let body = parse(input).unwrap();
+    let object =
+        Expression::from_iter([(ObjectKey::Identifier(Identifier::unchecked("env")), "prod")]);
+
+    let expected = Body::builder()
+        .add_attribute(("tags", object))
+        .add_block(
+            Block::builder("tags")
+                .add_attribute(("env", "prod"))
+                .build(),
+        )
+        .build();
+
+    assert_eq!(body, expected);
+
+    let structures: Vec<_> = body.iter().collect();
+    assert!(matches!(structures[0], Structure::Attribute(attr) if attr.expr.as_object().is_some()));
+    assert!(matches!(structures[1], Structure::Block(block) if block.identifier() == "tags"));
+
+    // Both forms are retained when formatting and serializing.
+    assert_eq!(crate::format::to_string(&body).unwrap(), input);
+    assert_eq!(crate::to_string(&body).unwrap(), input);
+}
+
+#[test]
+fn parse_mixed_object_keys() {
+    let input = "object = {\n  \"a\" = 1\n  b = 2\n}\n";
+
+    let body = parse(input).unwrap();
+
+    let expected = Body::from(Attribute::new(
+        "object",
+        Expression::from_iter([
+            (ObjectKey::Expression(Expression::String("a".into())), 1),
+            (ObjectKey::Identifier(Identifier::unchecked("b")), 2),
+        ]),
+    ));
+
+    assert_eq!(body, expected);
+    assert_eq!(crate::format::to_string(&body).unwrap(), input);
+    assert_eq!(crate::to_string(&body).unwrap(), input);
+}
+
+#[test]
+fn parse_max_structures() {
+    let input = "a = 1\nblock {\n  b = 2\n  c = 3\n}\n";
+
+    let options = ParseOptions::new().max_structures(4);
+    assert!(parse_with_options(input, &options).is_ok());
+
+    let options = ParseOptions::new().max_structures(3);
+    let err = parse_with_options(input, &options).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "number of structures exceeds the maximum of 3 in line 4, col 3"
+    );
+    assert_eq!(
+        err.location(),
+        Some(&Location {
+            line: 4,
+            col: 3,
+            offset: 24
+        })
+    );
+}
+
+#[test]
+fn parse_error_location() {
+    let input = "a = 1\nblock {\n  b = = 2\n}\n";
+
+    let err = parse(input).unwrap_err();
+    assert_eq!(
+        err.location(),
+        Some(&Location {
+            line: 3,
+            col: 7,
+            offset: 20
+        })
+    );
+    assert!(err.to_string().ends_with(" in line 3, col 7"));
+
+    // Deserialization reports the same location.
+    let err = crate::from_str::<crate::Value>(input).unwrap_err();
+    assert_eq!(err.location().map(|loc| loc.offset), Some(20));
+}
+
+#[test]
+fn parse_bare_strings() {
+    let input = "region = us-east-1\nenabled = true\nzone = var.zone\n";
+
+    let body = parse(input).unwrap();
+    assert_eq!(
+        body.attributes().next().unwrap().expr,
+        Expression::Variable(Identifier::unchecked("us-east-1").into())
+    );
+
+    let options = ParseOptions::new().bare_strings(true);
+    let body = parse_with_options(input, &options).unwrap();
+    let value: crate::Value = body.clone().into();
+
+    assert_eq!(
+        value,
+        crate::value!({
+            region = "us-east-1"
+            enabled = true
+            zone = "${var.zone}"
+        })
+    );
+
+    let block = parse_with_options("block { region = eu-west-1 }", &options).unwrap();
+    assert_eq!(
+        block
+            .blocks()
+            .next()
+            .unwrap()
+            .body
+            .attributes()
+            .next()
+            .unwrap()
+            .expr,
+        Expression::String("eu-west-1".into())
+    );
+}
+
+#[test]
+fn parse_retains_expression_trees() {
+    use crate::expr::{BinaryOp, BinaryOperator, Conditional, FuncCall, Operation, Variable};
+
+    let input = "x = a + b * c\ny = enabled ? max(1, 2) : var.default\n";
+    let body = parse(input).unwrap();
+
+    let expected = Body::builder()
+        .add_attribute((
+            "x",
+            Operation::Binary(BinaryOp::new(
+                Variable::unchecked("a"),
+                BinaryOperator::Plus,
+                Operation::Binary(BinaryOp::new(
+                    Variable::unchecked("b"),
+                    BinaryOperator::Mul,
+                    Variable::unchecked("c"),
+                )),
+            )),
+        ))
+        .add_attribute((
+            "y",
+            Conditional::new(
+                Variable::unchecked("enabled"),
+                FuncCall::builder("max").arg(1).arg(2).build(),
+                crate::expr::Traversal::builder(Variable::unchecked("var"))
+                    .attr("default")
+                    .build(),
+            ),
+        ))
+        .build();
+
+    assert_eq!(body, expected);
+
+    // The `Value` projection flattens non-literal expressions into strings.
+    assert_eq!(
+        crate::Value::from(body),
+        crate::value!({
+            x = "${a + b * c}"
+            y = "${enabled ? max(1, 2) : var.default}"
+        })
+    );
+}
+
+#[test]
+fn parse_raw_expressions() {
+    let input = indoc! {r#"
+        sum = a+b*  c # comment
+        cond = var.enabled?1:0
+        call = f( x ,[for v in y: v] ) /* comment */
+        obj = {a=1}[ "a" ]
+        block "label" {
+          list = [1,2,
+            3]
+          heredoc = <<-EOT
+            foo
+          EOT
+        }
+    "#};
+
+    let options = ParseOptions::new().raw_expressions(true);
+    let body = parse_with_options(input, &options).unwrap();
+
+    let expected = Body::builder()
+        .add_attribute(("sum", RawExpression::new("a+b*  c")))
+        .add_attribute(("cond", RawExpression::new("var.enabled?1:0")))
+        .add_attribute(("call", RawExpression::new("f( x ,[for v in y: v] )")))
+        .add_attribute(("obj", RawExpression::new("{a=1}[ \"a\" ]")))
+        .add_block(
+            Block::builder("block")
+                .add_label("label")
+                .add_attribute(("list", RawExpression::new("[1,2,\n    3]")))
+                .add_attribute(("heredoc", RawExpression::new("<<-EOT\n    foo\n  EOT")))
+                .build(),
+        )
+        .build();
+
+    assert_eq!(body, expected);
+
+    let formatted = crate::format::to_string(&body).unwrap();
+    let expected = indoc! {r#"
+        sum = a+b*  c
+        cond = var.enabled?1:0
+        call = f( x ,[for v in y: v] )
+        obj = {a=1}[ "a" ]
+
+        block "label" {
+          list = [1,2,
+            3]
+          heredoc = <<-EOT
+            foo
+          EOT
+        }
+    "#};
+
+    assert_eq!(formatted, expected);
+    assert!(parse_with_options("foo = a +", &options).is_err());
+}
+
+#[test]
+fn parse_raw_expressions_with_other_options() {
+    let options = ParseOptions::new()
+        .raw_expressions(true)
+        .bare_strings(true)
+        .unescape_template_sequences(true);
+
+    // Raw expressions take precedence over options which modify attribute values.
+    assert_eq!(
+        parse_with_options("a = foo\nb = \"$${x}\"\n", &options).unwrap(),
+        Body::builder()
+            .add_attribute(("a", RawExpression::new("foo")))
+            .add_attribute(("b", RawExpression::new("\"$${x}\"")))
+            .build()
+    );
+
+    // Literals-only mode is still enforced.
+    let options = options.literals_only(true);
+    assert!(parse_with_options("a = 1", &options).is_ok());
+    assert!(parse_with_options("a = var.b", &options).is_err());
+}
+
+#[test]
+fn parse_legacy_splat() {
+    use crate::eval::{Context, Evaluate};
+    use crate::expr::{Traversal, Variable};
+
+    let input = "legacy = list.*.id\nfull = list[*].id\n";
+    let body = parse(input).unwrap();
+
+    let expected = Body::builder()
+        .add_attribute((
+            "legacy",
+            Traversal::builder(Variable::unchecked("list"))
+                .attr_splat()
+                .attr("id")
+                .build(),
+        ))
+        .add_attribute((
+            "full",
+            Traversal::builder(Variable::unchecked("list"))
+                .full_splat()
+                .attr("id")
+                .build(),
+        ))
+        .build();
+
+    assert_eq!(body, expected);
+
+    // Both splat forms are semantically equivalent for attribute-only traversals.
+    let mut ctx = Context::new();
+    ctx.declare_var("list", crate::value!([{ id = 1 }, { id = 2 }]));
+
+    let mut attrs = body.attributes();
+    let legacy = attrs.next().unwrap().expr.evaluate(&ctx).unwrap();
+    let full = attrs.next().unwrap().expr.evaluate(&ctx).unwrap();
+    assert_eq!(legacy, full);
+    assert_eq!(legacy, crate::value!([1, 2]));
+
+    // The original syntax is retained when formatting.
+    assert_eq!(crate::format::to_string(&body).unwrap(), input);
+}
+
+#[test]
+fn comments_do_not_affect_equality() {
+    let input = indoc! {r#"
+        // leading comment
+        a = 1 # trailing comment
+
+        /* block comment */
+        block "label" {
+          # nested comment
+          b = [
+            1, // element comment
+            2,
+          ]
+        }
+    "#};
+
+    let uncommented = indoc! {r#"
+        a = 1
+        block "label" {
+          b = [1, 2]
+        }
+    "#};
+
+    assert_eq!(parse(input).unwrap(), parse(uncommented).unwrap());
+    assert_ne!(
+        parse(input).unwrap(),
+        parse("a = 2\nblock \"label\" { b = [1, 2] }").unwrap()
+    );
+
+    // Attached comments are compared by `==`, but not by `semantic_eq`.
+    let options = ParseOptions::new().preserve_comments(true);
+    let commented = parse_with_options(input, &options).unwrap();
+    assert_ne!(commented, parse(uncommented).unwrap());
+    assert!(commented.semantic_eq(&parse(uncommented).unwrap()));
+    assert!(!commented.semantic_eq(&parse("a = 1\nblock \"other\" { b = [1, 2] }").unwrap()));
+}
+
+#[test]
+fn multiline_block_comment_between_blocks() {
+    let input = indoc! {r#"
+        resource "a" "one" {
+          x = 1
+        }
+        /*
+          resource "a" "disabled" {
+            x = 2
+          }
+        */
+        resource "a" "two" {
+          x = 3
+        }
+    "#};
+
+    let body = parse(input).unwrap();
+
+    let expected = Body::builder()
+        .add_block(
+            Block::builder("resource")
+                .add_labels(["a", "one"])
+                .add_attribute(("x", 1))
+                .build(),
+        )
+        .add_block(
+            Block::builder("resource")
+                .add_labels(["a", "two"])
+                .add_attribute(("x", 3))
+                .build(),
+        )
+        .build();
+
+    assert_eq!(body, expected);
+
+    // Attached comments are plain strings which the formatter writes back as `#` line comments,
+    // so block comments cannot be re-emitted verbatim inside `/* */` and are dropped even when
+    // comments are preserved.
+    let options = ParseOptions::new().preserve_comments(true);
+    let body = parse_with_options(input, &options).unwrap();
+
+    assert_eq!(body, expected);
+}
+
+#[test]
+fn parse_numbers_preserve_integer_ness() {
+    let body = parse("a = 42\nb = -42\nc = 42.0\nd = 4.2e1\ne = -0").unwrap();
+    let numbers: Vec<_> = body
+        .attributes()
+        .map(|attr| match attr.expr() {
+            Expression::Number(number) => *number,
+            other => panic!("expected number, got {other:?}"),
+        })
+        .collect();
+
+    assert!(numbers[0].is_u64());
+    assert!(numbers[1].is_i64() && !numbers[1].is_u64());
+    assert!(numbers[2].is_f64());
+    assert!(numbers[3].is_f64());
+    assert!(numbers[4].is_u64());
+    assert_eq!(numbers[2], Number::from(42u64));
+}
+
+#[test]
+fn parse_literals_only() {
+    let options = ParseOptions::new().literals_only(true);
+
+    let input = indoc! {r#"
+        string = "foo"
+        number = -1.5
+        bool   = true
+        null   = null
+        array  = [1, -2, "three", [false]]
+        object = { a = 1, "b" = { c = null } }
+
+        block "label" {
+          heredoc = <<EOT
+        $${not_interpolated}
+        EOT
+        }
+    "#};
+
+    assert_eq!(
+        parse_with_options(input, &options).unwrap(),
+        parse(input).unwrap()
+    );
+
+    let tests = [
+        ("x = var.y", "traversal", (1, 5)),
+        ("x = 1 + 2", "operation", (1, 5)),
+        ("x = !true", "operation", (1, 5)),
+        ("x = true ? 1 : 2", "conditional", (1, 5)),
+        ("x = y", "variable", (1, 5)),
+        ("x = upper(\"a\")", "function call", (1, 5)),
+        ("x = [for v in [1]: v]", "for expression", (1, 5)),
+        ("x = (1)", "parenthesized expression", (1, 5)),
+        ("x = [1, [2, y]]", "variable", (1, 13)),
+        ("x = { a = 1, b = y }", "variable", (1, 18)),
+        ("x = { (y) = 1 }", "parenthesized expression", (1, 7)),
+        ("x = \"a${y}\"", "template interpolation", (1, 7)),
+        (
+            "x = \"%{ if true }a%{ endif }\"",
+            "template directive",
+            (1, 6),
+        ),
+        (
+            "block {\n  x = <<EOT\n${y}\nEOT\n}",
+            "template interpolation",
+            (3, 1),
+        ),
+    ];
+
+    for (input, kind, (line, col)) in tests {
+        let err = parse_with_options(input, &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("{kind} is not allowed in literals-only mode in line {line}, col {col}"),
+            "{input}"
+        );
+        assert!(parse(input).is_ok(), "{input}");
+    }
+}
+
+#[test]
+fn parse_preserve_comments() {
+    let input = indoc! {r#"
+        # The region.
+        #
+        // Used everywhere.
+        region = "us-east-1" # trailing comments are dropped
+
+        # not attached because of the blank line
+
+        /* block comments are dropped */
+        resource "aws_s3_bucket" "logs" {
+          # never public
+          acl = "private" /* inline */ }
+        a = 1 // not leading
+        b = 2
+    "#};
+
+    let options = ParseOptions::new().preserve_comments(true);
+    let body = parse_with_options(input, &options).unwrap();
+
+    let expected = Body::builder()
+        .add_attribute(
+            Attribute::new("region", "us-east-1").with_comment("The region.\n\nUsed everywhere."),
+        )
+        .add_block(
+            Block::builder("resource")
+                .add_labels(["aws_s3_bucket", "logs"])
+                .add_attribute(Attribute::new("acl", "private").with_comment("never public"))
+                .build(),
+        )
+        .add_attribute(("a", 1))
+        .add_attribute(("b", 2))
+        .build();
+
+    assert_eq!(body, expected);
+
+    // Comments are discarded by default.
+    assert!(parse(input)
+        .unwrap()
+        .attributes()
+        .all(|attr| attr.comment().is_none()));
+
+    // Comments survive a round-trip.
+    let formatted = crate::format::to_string(&body).unwrap();
+    assert_eq!(parse_with_options(&formatted, &options).unwrap(), body);
+}
+
+#[test]
+fn parse_preserve_spans() {
+    let input = indoc! {r#"
+        region  =  "us-east-1" # primary
+
+        resource "aws_s3_bucket" "logs" {
+          acl = <<EOT
+          private
+          EOT
+        }
+    "#};
+
+    let options = ParseOptions::new().preserve_spans(true);
+    let body = parse_with_options(input, &options).unwrap();
+
+    let region = body.get_attribute("region").unwrap();
+    assert_eq!(&input[region.span().unwrap()], r#"region  =  "us-east-1""#);
+
+    let block = body.blocks().next().unwrap();
+    assert_eq!(
+        &input[block.span().unwrap()],
+        "resource \"aws_s3_bucket\" \"logs\" {\n  acl = <<EOT\n  private\n  EOT\n}"
+    );
+
+    let acl = block.body.get_attribute("acl").unwrap();
+    assert_eq!(&input[acl.span().unwrap()], "acl = <<EOT\n  private\n  EOT");
+
+    // Spans are not recorded by default.
+    let unspanned = parse(input).unwrap();
+    assert!(unspanned.attributes().all(|attr| attr.span().is_none()));
+
+    // Spans are ignored when comparing structures.
+    assert_eq!(body, unspanned);
+
+    let shifted = parse_with_options(&format!("\n\n{input}"), &options).unwrap();
+    assert_ne!(
+        shifted.get_attribute("region").unwrap().span(),
+        region.span()
+    );
+    assert_eq!(shifted, body);
+}
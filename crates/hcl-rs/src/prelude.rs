@@ -0,0 +1,42 @@
+//! A curated set of commonly used types and traits.
+//!
+//! Glob-importing this module brings the core data model and the traits that are needed to work
+//! with it into scope:
+//!
+//! ```
+//! use hcl::prelude::*;
+//! # use std::error::Error;
+//! #
+//! # fn main() -> Result<(), Box<dyn Error>> {
+//! let body = Body::builder()
+//!     .add_attribute(("foo", Expression::from(1)))
+//!     .add_block(Block::builder("bar").add_attribute(("baz", true)).build())
+//!     .build();
+//!
+//! let evaluated = body.evaluate(&hcl::eval::Context::new())?;
+//! let mut formatter = hcl::format::Formatter::builder().build_vec();
+//!
+//! assert_eq!(
+//!     evaluated.format_string(&mut formatter)?,
+//!     "foo = 1\n\nbar {\n  baz = true\n}\n"
+//! );
+//! #   Ok(())
+//! # }
+//! ```
+//!
+//! The prelude contains:
+//!
+//! - The structure types [`Body`], [`Block`], [`BlockLabel`], [`Attribute`] and [`Structure`].
+//! - The expression types [`Expression`], [`Object`] and [`ObjectKey`].
+//! - The value types [`Value`], [`Map`] and [`Number`], as well as [`Identifier`].
+//! - The [`Format`] trait for formatting any of the above as HCL.
+//! - The [`Evaluate`] trait for evaluating expressions and structures.
+//!
+//! Less commonly used types like operations, templates or serializer internals are deliberately
+//! not included and need to be imported from their respective modules.
+
+pub use crate::eval::Evaluate;
+pub use crate::expr::{Expression, Object, ObjectKey};
+pub use crate::format::Format;
+pub use crate::structure::{Attribute, Block, BlockLabel, Body, Structure};
+pub use crate::{Identifier, Map, Number, Value};
@@ -26,6 +26,22 @@ pub(crate) const BLOCK_MARKER: &str = "$hcl::Block";
 /// - A *map-like* value (e.g. a map or struct).
 /// - A *sequence-like* value (e.g. a vector, slice or tuple) with map-like elements as described
 ///   above. In this case, multiple blocks with the same identifier are produced.
+/// - An `Option` of one of the above. `Some` is serialized like the wrapped value, while `None`
+///   produces no block at all. Since a missing field deserializes into `None`, this round-trips
+///   optional nested blocks.
+/// - An externally tagged *enum*. The variant name becomes the identifier of a block nested in
+///   the serialized block:
+///   - unit variants produce an empty nested block,
+///   - newtype variants serialize their value as nested block as described above, so a newtype
+///     variant wrapping a sequence produces multiple nested blocks. Values which cannot be
+///     serialized as block, like numbers or strings, produce an attribute named after the
+///     variant instead,
+///   - tuple variants produce one nested block per field,
+///   - struct variants produce a nested block containing the fields.
+///
+///   For example, the field `backend: Block<Backend>` holding
+///   `Backend::S3 { bucket: "b".into() }` is serialized as `backend { S3 { bucket = "b" } }`.
+///   Use `#[serde(rename_all = "snake_case")]` on the enum to get lowercase identifiers.
 ///
 /// Wrapping a type `T` that does not fulfil one of the criteria above in a `Block<T>` will result
 /// in serialization errors.
@@ -179,6 +195,7 @@ pub(crate) const LABELED_BLOCK_MARKER: &str = "$hcl::LabeledBlock";
 ///   arbitrarily deep to allow for any number of block labels.
 /// - A *sequence-like* value (e.g. a vector, slice or tuple) with map-like elements as described
 ///   above. In this case, multiple blocks with the same identifier and labels are produced.
+/// - An `Option` of one of the above, where `None` produces no block at all.
 ///
 /// Wrapping a type `T` that does not fulfil one of the criteria above in a [`LabeledBlock<T>`]
 /// will result in serialization errors.
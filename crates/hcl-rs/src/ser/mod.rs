@@ -1,7 +1,8 @@
 //! Serialize a Rust data structure into HCL data.
 //!
 //! This module provides the [`Serializer`] type and the convienince functions [`to_string`],
-//! [`to_vec`] and [`to_writer`] for serializing data to HCL.
+//! [`to_vec`] and [`to_writer`] for serializing data to HCL. The [`to_fmt`] function renders a
+//! [`Body`](crate::Body) inside a [`Display`](std::fmt::Display) implementation.
 //!
 //! Furthermore, the [`Block`] and [`LabeledBlock`] wrapper types, and the
 //! [`block`][crate::ser::block], [`labeled_block`][crate::ser::labeled_block] and
@@ -27,6 +28,18 @@
 //! Please note that these restrictions only apply to the top-level type that is serialized.
 //! Nested fields can have any type that is serializable.
 //!
+//! ## Object key order
+//!
+//! Serialization does not reorder keys. Map-like types are emitted in the order they are
+//! iterated, so struct fields keep their declaration order and [`hcl::Map`][crate::Map] (which
+//! is always insertion-ordered) keeps its insertion order. The output is thus deterministic for
+//! these types.
+//!
+//! Types with an unspecified iteration order, like `std::collections::HashMap`, produce output
+//! in that unspecified order. Use a `BTreeMap`, or convert the data into a
+//! [`Value`](crate::Value) and call [`Value::sort_keys`](crate::Value::sort_keys), if a stable
+//! key order is required.
+//!
 //! ## Serializing a custom type
 //!
 //! The following example will serialize the data as a deeply nested HCL attribute.
@@ -266,7 +279,7 @@
 pub(crate) mod blocks;
 
 pub use self::blocks::{block, doubly_labeled_block, labeled_block, Block, LabeledBlock};
-use crate::format::{Format, Formatter};
+use crate::format::{Format, Formatter, FormatterBuilder};
 use crate::structure::Body;
 use crate::{Error, Identifier, Result};
 use serde::ser::{self, Impossible, Serialize, SerializeStruct};
@@ -324,6 +337,14 @@ where
         self.formatter.into_inner()
     }
 
+    /// Returns the total number of bytes written to the underlying writer so far.
+    ///
+    /// This is useful for tracking the output size when streaming to a writer that cannot be
+    /// inspected afterwards.
+    pub fn bytes_written(&self) -> usize {
+        self.formatter.bytes_written()
+    }
+
     /// Serialize the given value as HCL via the serializer's `Formatter` to the underlying writer.
     ///
     /// # Errors
@@ -388,6 +409,11 @@ where
 
 /// Serialize the given value as an HCL string.
 ///
+/// Every attribute and block is terminated by a newline, so the output always ends with exactly
+/// one newline unless it is empty. See
+/// [`FormatterBuilder::trailing_newline`](crate::format::FormatterBuilder::trailing_newline) for
+/// controlling the output of empty bodies.
+///
 /// If you want to serialize the data structures provided by this crate (e.g.
 /// [`Body`](crate::Body)) consider using [`hcl::format::to_string`](crate::format::to_string)
 /// instead because it is more efficient.
@@ -422,6 +448,140 @@ where
     serializer.serialize(value)
 }
 
+/// Serialize the given value as an HCL string using a custom formatter configuration.
+///
+/// The `Formatter` is built from the provided [`FormatterBuilder`], so that the formatting
+/// options can be chosen without constructing a [`Serializer`] manually.
+///
+/// # Example
+///
+/// ```
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use hcl::format::Formatter;
+///
+/// let body = hcl::body!({ block { foo = [1, 2] } });
+///
+/// let formatter = Formatter::builder().indent_spaces(4).compact_arrays(true);
+///
+/// assert_eq!(
+///     hcl::ser::to_string_with(&body, formatter)?,
+///     "block {\n    foo = [1, 2]\n}\n"
+/// );
+/// #   Ok(())
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// Serialization fails if the type cannot be represented as HCL.
+pub fn to_string_with<T>(value: &T, builder: FormatterBuilder) -> Result<String>
+where
+    T: ?Sized + Serialize,
+{
+    let mut serializer = Serializer::with_formatter(builder.build_vec());
+    serializer.serialize_string(value)
+}
+
+/// Serialize the given value as HCL into the IO stream using a custom formatter configuration.
+///
+/// See [`to_string_with`] for more information.
+///
+/// # Errors
+///
+/// Serialization fails if any operation on the writer fails or if the type cannot be represented
+/// as HCL.
+pub fn to_writer_with<W, T>(writer: W, value: &T, builder: FormatterBuilder) -> Result<()>
+where
+    W: io::Write,
+    T: ?Sized + Serialize,
+{
+    let mut serializer = Serializer::with_formatter(builder.build(writer));
+    serializer.serialize(value)
+}
+
+/// Format the given [`Body`] as HCL into a [`fmt::Formatter`].
+///
+/// This is the recommended way to render HCL inside a [`Display`](fmt::Display) implementation
+/// since it writes directly into the formatter without an intermediate byte buffer.
+///
+/// # Example
+///
+/// ```
+/// use hcl::Body;
+/// use std::fmt;
+///
+/// struct Config(Body);
+///
+/// impl fmt::Display for Config {
+///     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+///         hcl::ser::to_fmt(f, &self.0)
+///     }
+/// }
+///
+/// let config = Config(Body::builder().add_attribute(("foo", "bar")).build());
+///
+/// assert_eq!(config.to_string(), "foo = \"bar\"\n");
+/// ```
+///
+/// # Errors
+///
+/// Formatting fails if writing to the formatter fails.
+pub fn to_fmt(f: &mut fmt::Formatter, body: &Body) -> fmt::Result {
+    format_fmt(f, body)
+}
+
+// Formats `value` with the default formatter settings directly into a `fmt::Formatter`. This
+// backs the `Display` impls of the HCL structure types.
+pub(crate) fn format_fmt<T>(f: &mut fmt::Formatter, value: &T) -> fmt::Result
+where
+    T: ?Sized + Format,
+{
+    let mut writer = FmtWriter {
+        f,
+        pending: Vec::new(),
+    };
+
+    crate::format::to_writer(&mut writer, value).map_err(|_| fmt::Error)?;
+
+    if writer.pending.is_empty() {
+        Ok(())
+    } else {
+        Err(fmt::Error)
+    }
+}
+
+// Adapter for writing the UTF-8 bytes produced by the `Formatter` into a `fmt::Formatter`.
+// Incomplete UTF-8 sequences at the end of a write are held back until the next write completes
+// them.
+struct FmtWriter<'a, 'b> {
+    f: &'a mut fmt::Formatter<'b>,
+    pending: Vec<u8>,
+}
+
+impl io::Write for FmtWriter<'_, '_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut bytes = std::mem::take(&mut self.pending);
+        bytes.extend_from_slice(buf);
+
+        let (valid, rest) = match std::str::from_utf8(&bytes) {
+            Ok(s) => (s, &[][..]),
+            Err(err) if err.error_len().is_none() => {
+                let (valid, rest) = bytes.split_at(err.valid_up_to());
+                (std::str::from_utf8(valid).unwrap(), rest)
+            }
+            Err(err) => return Err(io::Error::new(io::ErrorKind::InvalidData, err)),
+        };
+
+        self.f.write_str(valid).map_err(io::Error::other)?;
+        self.pending = rest.to_vec();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
 pub(crate) struct StringSerializer;
 
 impl ser::Serializer for StringSerializer {
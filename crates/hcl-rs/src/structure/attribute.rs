@@ -1,9 +1,11 @@
 //! Types to represent and build HCL attributes.
 
-use crate::expr::Expression;
-use crate::{Identifier, Value};
+use crate::expr::{Expression, Object, ObjectKey};
+use crate::ser::format_fmt;
+use crate::{Identifier, Number, Value};
 use serde::Deserialize;
-use std::iter;
+use std::ops::Range;
+use std::{fmt, iter};
 
 /// Represents an HCL attribute which consists of an attribute key and a value expression.
 ///
@@ -15,12 +17,24 @@ use std::iter;
 ///
 /// Use [`Attribute::new`] to construct an [`Attribute`] from a value that is convertible to this
 /// crate's [`Expression`] type.
-#[derive(Deserialize, Debug, PartialEq, Eq, Clone)]
+#[derive(Deserialize, Debug, Eq, Clone)]
 pub struct Attribute {
     /// The HCL attribute's key.
     pub key: Identifier,
     /// The value expression of the HCL attribute.
     pub expr: Expression,
+    /// An optional comment which is written as `#` line comment above the attribute when it is
+    /// formatted. Multi-line comments produce one line comment per line.
+    #[serde(default)]
+    pub comment: Option<String>,
+    #[serde(skip)]
+    pub(crate) span: Option<Range<usize>>,
+}
+
+impl PartialEq for Attribute {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key && self.expr == other.expr && self.comment == other.comment
+    }
 }
 
 impl Attribute {
@@ -34,9 +48,32 @@ impl Attribute {
         Attribute {
             key: key.into(),
             expr: expr.into(),
+            comment: None,
+            span: None,
         }
     }
 
+    /// Attaches a comment to the attribute which is written above it when formatted.
+    ///
+    /// Consumes `self` and returns the `Attribute` with the comment set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hcl::Attribute;
+    ///
+    /// let attr = Attribute::new("region", "us-east-1").with_comment("primary region");
+    ///
+    /// assert_eq!(hcl::to_string(&attr).unwrap(), "# primary region\nregion = \"us-east-1\"\n");
+    /// ```
+    pub fn with_comment<C>(mut self, comment: C) -> Attribute
+    where
+        C: Into<String>,
+    {
+        self.comment = Some(comment.into());
+        self
+    }
+
     /// Returns a reference to the attribute key.
     pub fn key(&self) -> &str {
         &self.key
@@ -46,6 +83,67 @@ impl Attribute {
     pub fn expr(&self) -> &Expression {
         &self.expr
     }
+
+    /// Returns the comment attached to the attribute, if any.
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+
+    /// Returns the byte range of the attribute in the input it was parsed from.
+    ///
+    /// This is only available if the attribute was parsed with
+    /// [`ParseOptions::preserve_spans`][crate::ParseOptions::preserve_spans] enabled. The span
+    /// always refers to the original source and is not updated if the attribute is modified
+    /// after parsing, so it can only be used to locate the attribute's source text. Spans are
+    /// ignored by the formatter, by serialization and when comparing attributes for equality.
+    pub fn span(&self) -> Option<Range<usize>> {
+        self.span.clone()
+    }
+
+    /// If the attribute's expression is a Boolean, represent it as bool. Returns None otherwise.
+    pub fn as_bool(&self) -> Option<bool> {
+        self.expr.as_bool()
+    }
+
+    /// If the attribute's expression is a Number, represent it as i64 if possible. Returns None
+    /// otherwise.
+    pub fn as_i64(&self) -> Option<i64> {
+        self.as_number().and_then(Number::as_i64)
+    }
+
+    /// If the attribute's expression is a Number, returns the associated Number. Returns None
+    /// otherwise.
+    pub fn as_number(&self) -> Option<&Number> {
+        self.expr.as_number()
+    }
+
+    /// If the attribute's expression is an Object, returns the associated Object. Returns None
+    /// otherwise.
+    pub fn as_object(&self) -> Option<&Object<ObjectKey, Expression>> {
+        self.expr.as_object()
+    }
+
+    /// If the attribute's expression is a String, returns the associated str. Returns None
+    /// otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hcl::Attribute;
+    ///
+    /// assert_eq!(Attribute::new("foo", "bar").as_str(), Some("bar"));
+    /// assert_eq!(Attribute::new("foo", 1).as_str(), None);
+    /// ```
+    pub fn as_str(&self) -> Option<&str> {
+        self.expr.as_str()
+    }
+}
+
+impl fmt::Display for Attribute {
+    /// Formats the `Attribute` as HCL using the default formatter settings.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format_fmt(f, self)
+    }
 }
 
 impl From<Attribute> for Value {
@@ -1,8 +1,11 @@
 //! Types to represent and build HCL blocks.
 
 use super::{Attribute, Body, BodyBuilder, Structure};
+use crate::ser::format_fmt;
 use crate::Identifier;
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::ops::Range;
 
 /// Represents an HCL block which consists of a block identifier, zero or more block labels and a
 /// block body.
@@ -14,7 +17,7 @@ use serde::{Deserialize, Serialize};
 ///   body
 /// }
 /// ```
-#[derive(Deserialize, Debug, PartialEq, Eq, Clone)]
+#[derive(Deserialize, Debug, Eq, Clone)]
 pub struct Block {
     /// The block identifier.
     pub identifier: Identifier,
@@ -22,6 +25,21 @@ pub struct Block {
     pub labels: Vec<BlockLabel>,
     /// Represents the `Block`'s body.
     pub body: Body,
+    /// An optional comment which is written as `#` line comment above the block when it is
+    /// formatted. Multi-line comments produce one line comment per line.
+    #[serde(default)]
+    pub comment: Option<String>,
+    #[serde(skip)]
+    pub(crate) span: Option<Range<usize>>,
+}
+
+impl PartialEq for Block {
+    fn eq(&self, other: &Self) -> bool {
+        self.identifier == other.identifier
+            && self.labels == other.labels
+            && self.body == other.body
+            && self.comment == other.comment
+    }
 }
 
 impl Block {
@@ -34,6 +52,8 @@ impl Block {
             identifier: ident.into(),
             labels: Vec::new(),
             body: Body::default(),
+            comment: None,
+            span: None,
         }
     }
 
@@ -60,6 +80,62 @@ impl Block {
     pub fn body(&self) -> &Body {
         &self.body
     }
+
+    /// Returns the comment attached to the block, if any.
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+
+    /// Returns the byte range of the block in the input it was parsed from.
+    ///
+    /// This is only available if the block was parsed with
+    /// [`ParseOptions::preserve_spans`][crate::ParseOptions::preserve_spans] enabled. The span
+    /// always refers to the original source and is not updated if the block or its body is
+    /// modified after parsing, so it can only be used to locate the block's source text. Spans
+    /// are ignored by the formatter, by serialization and when comparing blocks for equality.
+    pub fn span(&self) -> Option<Range<usize>> {
+        self.span.clone()
+    }
+
+    /// Attaches a comment to the block which is written above it when formatted.
+    ///
+    /// Consumes `self` and returns the `Block` with the comment set.
+    pub fn with_comment<C>(mut self, comment: C) -> Block
+    where
+        C: Into<String>,
+    {
+        self.comment = Some(comment.into());
+        self
+    }
+
+    /// Returns `true` if any of the block's labels is equal to `label`.
+    ///
+    /// Labels are compared by their string value, so it does not matter whether a label is
+    /// quoted or a bare identifier.
+    ///
+    /// ```
+    /// use hcl::{Block, Identifier};
+    ///
+    /// let block = Block::builder("resource")
+    ///     .add_label("aws_s3_bucket")
+    ///     .add_label(Identifier::new("bucket")?)
+    ///     .build();
+    ///
+    /// assert!(block.has_label("aws_s3_bucket"));
+    /// assert!(block.has_label("bucket"));
+    /// assert!(!block.has_label("resource"));
+    /// # Ok::<(), hcl::Error>(())
+    /// ```
+    pub fn has_label(&self, label: &str) -> bool {
+        self.labels.iter().any(|l| l.as_str() == label)
+    }
+}
+
+impl fmt::Display for Block {
+    /// Formats the `Block` as HCL using the default formatter settings.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format_fmt(f, self)
+    }
 }
 
 impl<I, B> From<(I, B)> for Block
@@ -73,6 +149,8 @@ where
             identifier: ident.into(),
             labels: Vec::new(),
             body: body.into_iter().collect(),
+            comment: None,
+            span: None,
         }
     }
 }
@@ -90,6 +168,8 @@ where
             identifier: ident.into(),
             labels: labels.into_iter().map(Into::into).collect(),
             body: body.into_iter().collect(),
+            comment: None,
+            span: None,
         }
     }
 }
@@ -200,6 +280,7 @@ pub struct BlockBuilder {
     identifier: Identifier,
     labels: Vec<BlockLabel>,
     body: BodyBuilder,
+    comment: Option<String>,
 }
 
 impl BlockBuilder {
@@ -213,9 +294,21 @@ impl BlockBuilder {
             identifier: identifier.into(),
             labels: Vec::new(),
             body: Body::builder(),
+            comment: None,
         }
     }
 
+    /// Sets the comment which is written above the block when formatted.
+    ///
+    /// Consumes `self` and returns a new `BlockBuilder`.
+    pub fn comment<C>(mut self, comment: C) -> BlockBuilder
+    where
+        C: Into<String>,
+    {
+        self.comment = Some(comment.into());
+        self
+    }
+
     /// Adds a `BlockLabel`.
     ///
     /// Consumes `self` and returns a new `BlockBuilder`.
@@ -227,6 +320,26 @@ impl BlockBuilder {
         self
     }
 
+    /// Adds a bare identifier `BlockLabel`.
+    ///
+    /// Consumes `self` and returns a new `BlockBuilder`.
+    pub fn add_identifier_label<I>(self, identifier: I) -> BlockBuilder
+    where
+        I: Into<Identifier>,
+    {
+        self.add_label(BlockLabel::Identifier(identifier.into()))
+    }
+
+    /// Adds a quoted string `BlockLabel`.
+    ///
+    /// Consumes `self` and returns a new `BlockBuilder`.
+    pub fn add_string_label<S>(self, string: S) -> BlockBuilder
+    where
+        S: Into<String>,
+    {
+        self.add_label(BlockLabel::String(string.into()))
+    }
+
     /// Adds `BlockLabel`s from an iterator.
     ///
     /// Consumes `self` and returns a new `BlockBuilder`.
@@ -314,6 +427,8 @@ impl BlockBuilder {
             identifier: self.identifier,
             labels: self.labels,
             body: self.body.build(),
+            comment: self.comment,
+            span: None,
         }
     }
 }
@@ -4,14 +4,65 @@ use super::iter::{
     Attributes, AttributesMut, Blocks, BlocksMut, IntoAttributes, IntoBlocks, Iter, IterMut,
 };
 use super::ser::BodySerializer;
-use super::{Attribute, Block, Structure};
-use crate::ser::with_internal_serialization;
-use crate::Result;
+use super::{Attribute, Block, BlockLabel, Structure};
+use crate::ser::{format_fmt, with_internal_serialization};
+use crate::{Error, Result, Value};
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
 
 /// Represents an HCL config file body.
 ///
 /// A `Body` consists of zero or more [`Attribute`] and [`Block`] HCL structures.
+///
+/// Comments are discarded when parsing unless
+/// [`ParseOptions::preserve_comments`][crate::ParseOptions::preserve_comments] is enabled. By
+/// default, bodies parsed from inputs that only differ in comments or whitespace therefore
+/// compare equal. Attached comments are part of the structures they belong to and are compared
+/// by `==`, use [`Body::semantic_eq`] to compare bodies regardless of their comments.
+///
+/// # Deserialization from other formats
+///
+/// Besides being deserialized from HCL via [`hcl::from_str`][crate::from_str], `Body` and the
+/// types it consists of can be deserialized from any self-describing format supported by
+/// `serde`. In this data model, [`Structure`], [`BlockLabel`][crate::BlockLabel] and
+/// [`Expression`][crate::Expression] are externally tagged enums, i.e. each of them is a map with
+/// a single key naming the variant:
+///
+/// ```
+/// use hcl::Body;
+/// use serde_json::json;
+///
+/// let json = json!([
+///     { "Attribute": { "key": "name", "expr": { "String": "web" } } },
+///     {
+///         "Block": {
+///             "identifier": "resource",
+///             "labels": [{ "String": "aws_instance" }],
+///             "body": [
+///                 { "Attribute": { "key": "count", "expr": { "Number": 2 } } }
+///             ]
+///         }
+///     }
+/// ]);
+///
+/// let body: Body = serde_json::from_value(json)?;
+///
+/// assert_eq!(
+///     body,
+///     hcl::body!({
+///         name = "web"
+///         resource "aws_instance" {
+///             count = 2
+///         }
+///     })
+/// );
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+///
+/// Note that serializing a `Body` with a serializer other than the one provided by this crate
+/// does not produce this tagged representation, but plain values that are suitable for
+/// generating JSON, so the output cannot be deserialized into a `Body` again.
 #[derive(Deserialize, Serialize, Debug, PartialEq, Eq, Default, Clone)]
 #[serde(rename = "$hcl::Body")]
 pub struct Body(pub Vec<Structure>);
@@ -35,6 +86,76 @@ impl Body {
         BodyBuilder::default()
     }
 
+    /// Returns the number of structures in the `Body`.
+    ///
+    /// Only the top-level structures are counted, not those nested within blocks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hcl::{Attribute, Block, Body};
+    ///
+    /// let body = Body::builder()
+    ///     .add_attribute(("a", 1))
+    ///     .add_block(Block::builder("b").add_attribute(("c", 2)).build())
+    ///     .build();
+    ///
+    /// assert_eq!(body.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if the `Body` contains no structures.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hcl::Body;
+    ///
+    /// assert!(Body::default().is_empty());
+    /// assert!(!Body::builder().add_attribute(("a", 1)).build().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns a reference to the structure at `index`, or `None` if it is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hcl::{Attribute, Body, Structure};
+    ///
+    /// let body = Body::from([Attribute::new("a", 1), Attribute::new("b", 2)]);
+    ///
+    /// assert_eq!(body.get(1), Some(&Structure::from(Attribute::new("b", 2))));
+    /// assert_eq!(body.get(2), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&Structure> {
+        self.0.get(index)
+    }
+
+    /// Returns a mutable reference to the structure at `index`, or `None` if it is out of
+    /// bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hcl::{Attribute, Body, Expression};
+    ///
+    /// let mut body = Body::from([Attribute::new("version", "1.0.0")]);
+    ///
+    /// if let Some(attr) = body.get_mut(0).and_then(|s| s.as_attribute_mut()) {
+    ///     attr.expr = Expression::from("1.1.0");
+    /// }
+    ///
+    /// assert_eq!(body, Body::from([Attribute::new("version", "1.1.0")]));
+    /// ```
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut Structure> {
+        self.0.get_mut(index)
+    }
+
     /// An iterator visiting all structures within the `Body`. The iterator element type is `&'a
     /// Structure`.
     ///
@@ -181,6 +302,29 @@ impl Body {
     /// let vec: Vec<&Block> = body.blocks().collect();
     /// assert_eq!(vec, [&Block::new("b")]);
     /// ```
+    ///
+    /// Collect the identifiers of all blocks, or the labels of all `resource` blocks:
+    ///
+    /// ```
+    /// use hcl::{Block, Body};
+    ///
+    /// let body = Body::builder()
+    ///     .add_attribute(("version", 2))
+    ///     .add_block(Block::builder("resource").add_labels(["aws_s3_bucket", "a"]).build())
+    ///     .add_block(Block::builder("variable").add_label("region").build())
+    ///     .add_block(Block::builder("resource").add_labels(["aws_s3_bucket", "b"]).build())
+    ///     .build();
+    ///
+    /// let identifiers: Vec<&str> = body.blocks().map(|block| block.identifier()).collect();
+    /// assert_eq!(identifiers, ["resource", "variable", "resource"]);
+    ///
+    /// let names: Vec<&str> = body
+    ///     .blocks()
+    ///     .filter(|block| block.identifier() == "resource")
+    ///     .map(|block| block.labels()[1].as_str())
+    ///     .collect();
+    /// assert_eq!(names, ["a", "b"]);
+    /// ```
     pub fn blocks(&self) -> Blocks<'_> {
         Blocks::new(self)
     }
@@ -234,6 +378,295 @@ impl Body {
     pub fn into_blocks(self) -> IntoBlocks {
         IntoBlocks::new(self)
     }
+
+    /// Returns the first top-level attribute with the given key, or `None` if there is no such
+    /// attribute.
+    ///
+    /// A `Body` may contain multiple attributes with the same key, e.g. if it was constructed
+    /// programmatically. Use [`get_attributes`][Body::get_attributes] to visit all of them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hcl::{Body, Expression};
+    ///
+    /// let body = Body::builder()
+    ///     .add_attribute(("version", "1.0.0"))
+    ///     .add_attribute(("name", "app"))
+    ///     .build();
+    ///
+    /// let attr = body.get_attribute("version").unwrap();
+    /// assert_eq!(attr.expr(), &Expression::from("1.0.0"));
+    ///
+    /// assert!(body.get_attribute("missing").is_none());
+    /// ```
+    pub fn get_attribute(&self, key: &str) -> Option<&Attribute> {
+        self.attributes().find(|attr| attr.key() == key)
+    }
+
+    /// Returns a mutable reference to the first top-level attribute with the given key, or
+    /// `None` if there is no such attribute.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hcl::{Attribute, Body};
+    ///
+    /// let mut body = Body::from([Attribute::new("version", "1.0.0")]);
+    ///
+    /// if let Some(attr) = body.get_attribute_mut("version") {
+    ///     attr.expr = "1.1.0".into();
+    /// }
+    ///
+    /// assert_eq!(body, Body::from([Attribute::new("version", "1.1.0")]));
+    /// ```
+    pub fn get_attribute_mut(&mut self, key: &str) -> Option<&mut Attribute> {
+        self.attributes_mut().find(|attr| attr.key() == key)
+    }
+
+    /// An iterator visiting all top-level attributes with the given key in the order they appear
+    /// in the `Body`. The iterator element type is `&'a Attribute`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hcl::{Attribute, Body};
+    ///
+    /// let body = Body::from([
+    ///     Attribute::new("a", 1),
+    ///     Attribute::new("b", 2),
+    ///     Attribute::new("a", 3),
+    /// ]);
+    ///
+    /// let attrs: Vec<&Attribute> = body.get_attributes("a").collect();
+    /// assert_eq!(attrs, [&Attribute::new("a", 1), &Attribute::new("a", 3)]);
+    /// ```
+    pub fn get_attributes<'a>(&'a self, key: &'a str) -> impl Iterator<Item = &'a Attribute> + 'a {
+        self.attributes().filter(move |attr| attr.key() == key)
+    }
+
+    /// Merges `other` into `self`, e.g. to apply a layer of overrides to a base configuration.
+    ///
+    /// The structures of `other` are merged in order using the following policy:
+    ///
+    /// - An attribute replaces the value of a top-level attribute in `self` with the same key,
+    ///   keeping its position. If there is no such attribute, it is appended.
+    /// - A block is merged recursively into a top-level block in `self` with the same identifier
+    ///   and labels using the same policy. If there is no such block, it is appended. Labels are
+    ///   compared by their string value.
+    ///
+    /// Each structure that was present in `self` before the merge is matched at most once, in
+    /// order. The n-th attribute with a given key in `other` therefore replaces the n-th attribute
+    /// with that key in `self`, and the n-th block with a given identifier and labels is merged
+    /// into the n-th such block in `self`. Any further occurrences are appended. This keeps
+    /// repeatable blocks like `ingress` apart instead of merging all of them into the first match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let mut base = hcl::body!({
+    ///     region = "eu-west-1"
+    ///     resource "aws_s3_bucket" "logs" {
+    ///         acl = "private"
+    ///         versioning = false
+    ///     }
+    /// });
+    ///
+    /// let overrides = hcl::body!({
+    ///     region = "eu-central-1"
+    ///     resource "aws_s3_bucket" "logs" {
+    ///         versioning = true
+    ///     }
+    ///     resource "aws_s3_bucket" "data" {
+    ///         acl = "private"
+    ///     }
+    /// });
+    ///
+    /// base.merge(overrides);
+    ///
+    /// let expected = hcl::body!({
+    ///     region = "eu-central-1"
+    ///     resource "aws_s3_bucket" "logs" {
+    ///         acl = "private"
+    ///         versioning = true
+    ///     }
+    ///     resource "aws_s3_bucket" "data" {
+    ///         acl = "private"
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(base, expected);
+    /// ```
+    pub fn merge(&mut self, other: Body) {
+        // Only structures which were present before the merge are matched, and each of them only
+        // once, so that repeated structures in `other` are never merged into each other.
+        let len = self.0.len();
+        let mut matched = vec![false; len];
+
+        for structure in other {
+            let existing = self.0[..len]
+                .iter_mut()
+                .zip(&mut matched)
+                .filter(|(_, matched)| !**matched)
+                .find(|(existing, _)| match (&**existing, &structure) {
+                    (Structure::Attribute(existing), Structure::Attribute(attr)) => {
+                        existing.key == attr.key
+                    }
+                    (Structure::Block(existing), Structure::Block(block)) => {
+                        existing.identifier == block.identifier
+                            && existing
+                                .labels
+                                .iter()
+                                .map(BlockLabel::as_str)
+                                .eq(block.labels.iter().map(BlockLabel::as_str))
+                    }
+                    (_, _) => false,
+                })
+                .map(|(existing, matched)| {
+                    *matched = true;
+                    existing
+                });
+
+            match (existing, structure) {
+                (Some(Structure::Attribute(existing)), Structure::Attribute(attr)) => {
+                    existing.expr = attr.expr;
+                }
+                (Some(Structure::Block(existing)), Structure::Block(block)) => {
+                    existing.body.merge(block.body);
+                }
+                (_, structure) => self.0.push(structure),
+            }
+        }
+    }
+
+    /// An iterator visiting all blocks within the `Body` which have the given identifier. The
+    /// iterator element type is `&'a Block`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hcl::{Block, Body};
+    ///
+    /// let body = Body::builder()
+    ///     .add_block(Block::builder("resource").add_labels(["aws_s3_bucket", "a"]).build())
+    ///     .add_block(Block::builder("variable").add_label("region").build())
+    ///     .add_block(Block::builder("resource").add_labels(["aws_s3_bucket", "b"]).build())
+    ///     .build();
+    ///
+    /// assert_eq!(body.blocks_with_identifier("resource").count(), 2);
+    /// assert_eq!(body.blocks_with_identifier("output").count(), 0);
+    /// ```
+    pub fn blocks_with_identifier<'a>(
+        &'a self,
+        identifier: &'a str,
+    ) -> impl Iterator<Item = &'a Block> + 'a {
+        self.blocks()
+            .filter(move |block| block.identifier() == identifier)
+    }
+
+    /// Returns the first block within the `Body` which has the given identifier and exactly the
+    /// given labels, or `None` if there is no such block.
+    ///
+    /// Labels are compared by their string value, so it does not matter whether a label is
+    /// quoted or a bare identifier.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hcl::{Block, Body};
+    ///
+    /// let body = Body::builder()
+    ///     .add_block(
+    ///         Block::builder("resource")
+    ///             .add_labels(["aws_s3_bucket", "bucket"])
+    ///             .add_attribute(("acl", "private"))
+    ///             .build(),
+    ///     )
+    ///     .build();
+    ///
+    /// let block = body.get_block("resource", &["aws_s3_bucket", "bucket"]).unwrap();
+    /// assert_eq!(block.body().len(), 1);
+    ///
+    /// assert!(body.get_block("resource", &["aws_s3_bucket"]).is_none());
+    /// ```
+    pub fn get_block(&self, identifier: &str, labels: &[&str]) -> Option<&Block> {
+        self.blocks().find(|block| {
+            block.identifier() == identifier
+                && block
+                    .labels()
+                    .iter()
+                    .map(BlockLabel::as_str)
+                    .eq(labels.iter().copied())
+        })
+    }
+
+    /// Returns `true` if both bodies contain the same attributes and blocks in the same order.
+    ///
+    /// Only attribute keys and expressions, and block identifiers, labels and bodies are
+    /// compared. Any other information attached to structures, such as comments, is ignored. This
+    /// can be used to verify that formatting or otherwise rewriting a body did not change its
+    /// meaning.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hcl::{Attribute, Body};
+    ///
+    /// let commented = Body::from(Attribute::new("a", 1).with_comment("the answer"));
+    /// let uncommented = Body::from(Attribute::new("a", 1));
+    ///
+    /// assert_ne!(commented, uncommented);
+    /// assert!(commented.semantic_eq(&uncommented));
+    /// assert!(!commented.semantic_eq(&Body::from(Attribute::new("a", 2))));
+    /// ```
+    pub fn semantic_eq(&self, other: &Body) -> bool {
+        self.0.len() == other.0.len()
+            && self.iter().zip(other.iter()).all(|(a, b)| a.semantic_eq(b))
+    }
+
+    /// Converts the `Body` into a [`Value`] in the same way as `Value::from(body)`, but fails
+    /// with an error instead of overflowing the stack if blocks are nested deeper than
+    /// `max_depth`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hcl::{Block, Body};
+    ///
+    /// let body = Body::from(Block::builder("a").add_block(Block::new("b")).build());
+    ///
+    /// assert!(body.clone().try_into_value(2).is_ok());
+    /// assert!(body.try_into_value(1).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the block nesting depth of the `Body` exceeds `max_depth`.
+    pub fn try_into_value(self, max_depth: usize) -> Result<Value> {
+        let depth = self.nesting_depth();
+
+        if depth > max_depth {
+            return Err(Error::new(format!(
+                "block nesting depth of {depth} exceeds the maximum of {max_depth}"
+            )));
+        }
+
+        Ok(Value::from(self))
+    }
+
+    // Determines the maximum block nesting depth without recursing so that this is safe to call
+    // on arbitrarily deep bodies.
+    fn nesting_depth(&self) -> usize {
+        let mut max_depth = 0;
+        let mut stack = vec![(self, 0)];
+
+        while let Some((body, depth)) = stack.pop() {
+            max_depth = max_depth.max(depth);
+            stack.extend(body.blocks().map(|block| (&block.body, depth + 1)));
+        }
+
+        max_depth
+    }
 }
 
 impl<T> From<T> for Body
@@ -281,6 +714,41 @@ where
     }
 }
 
+impl FromStr for Body {
+    type Err = Error;
+
+    /// Parses a HCL `Body` from a string.
+    ///
+    /// This is equivalent to calling [`hcl::parse`][crate::parse].
+    ///
+    /// ```
+    /// use hcl::Body;
+    ///
+    /// let body: Body = r#"foo = "bar""#.parse()?;
+    ///
+    /// assert_eq!(body, Body::builder().add_attribute(("foo", "bar")).build());
+    /// # Ok::<(), hcl::Error>(())
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::parse(s)
+    }
+}
+
+impl fmt::Display for Body {
+    /// Formats the `Body` as HCL using the default formatter settings.
+    ///
+    /// ```
+    /// use hcl::Body;
+    ///
+    /// let body = Body::builder().add_attribute(("foo", "bar")).build();
+    ///
+    /// assert_eq!(body.to_string(), "foo = \"bar\"\n");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format_fmt(f, self)
+    }
+}
+
 /// `BodyBuilder` builds a HCL [`Body`].
 ///
 /// The builder allows to build the `Body` by adding attributes and other nested blocks via chained
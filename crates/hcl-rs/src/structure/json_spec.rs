@@ -53,6 +53,8 @@ impl IntoJsonSpec for Block {
                     identifier: Identifier::unchecked(label.into_inner()),
                     labels: labels.collect(),
                     body: self.body,
+                    comment: None,
+                    span: None,
                 };
 
                 JsonNode::Map(block.into_json_nodes())
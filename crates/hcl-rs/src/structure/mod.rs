@@ -7,6 +7,7 @@
 //! - [`BlockBuilder`]: provides functionality for building `Block`s
 //! - [`Body`]: represent the body of an HCL configuration or block
 //! - [`BodyBuilder`]: provides functionality for building `Body`s
+//! - [`StructureVisitor`]: a visitor for mutating the structures of a `Body` recursively
 //!
 //! ## Examples
 //!
@@ -54,15 +55,20 @@ mod json_spec;
 mod ser;
 #[cfg(test)]
 mod tests;
+mod validate;
+mod visit;
 
 pub(crate) use self::json_spec::IntoJsonSpec;
 pub use self::{
     attribute::Attribute,
     block::{Block, BlockBuilder, BlockLabel},
     body::{Body, BodyBuilder},
+    visit::StructureVisitor,
 };
+use crate::ser::format_fmt;
 use crate::Value;
 use serde::Deserialize;
+use std::fmt;
 
 /// Represents an HCL structure.
 ///
@@ -135,6 +141,28 @@ impl Structure {
             Structure::Attribute(_) => None,
         }
     }
+
+    /// Returns `true` if both structures are semantically equal.
+    ///
+    /// See [`Body::semantic_eq`] for details.
+    pub fn semantic_eq(&self, other: &Structure) -> bool {
+        match (self, other) {
+            (Structure::Attribute(a), Structure::Attribute(b)) => {
+                a.key == b.key && a.expr == b.expr
+            }
+            (Structure::Block(a), Structure::Block(b)) => {
+                a.identifier == b.identifier && a.labels == b.labels && a.body.semantic_eq(&b.body)
+            }
+            (_, _) => false,
+        }
+    }
+}
+
+impl fmt::Display for Structure {
+    /// Formats the `Structure` as HCL using the default formatter settings.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format_fmt(f, self)
+    }
 }
 
 impl From<Structure> for Value {
@@ -31,9 +31,11 @@ impl ser::Serialize for Attribute {
         if in_internal_serialization() {
             STRUCTURE_HANDLES.with(|sh| sh.serialize(self.clone(), serializer))
         } else {
-            let mut s = serializer.serialize_struct("Attribute", 2)?;
+            let len = 2 + usize::from(self.comment.is_some());
+            let mut s = serializer.serialize_struct("Attribute", len)?;
             s.serialize_field("key", &self.key)?;
             s.serialize_field("expr", &self.expr)?;
+            serialize_comment(&mut s, self.comment.as_deref())?;
             s.end()
         }
     }
@@ -47,15 +49,29 @@ impl ser::Serialize for Block {
         if in_internal_serialization() {
             STRUCTURE_HANDLES.with(|sh| sh.serialize(self.clone(), serializer))
         } else {
-            let mut s = serializer.serialize_struct("Block", 3)?;
+            let len = 3 + usize::from(self.comment.is_some());
+            let mut s = serializer.serialize_struct("Block", len)?;
             s.serialize_field("identifier", &self.identifier)?;
             s.serialize_field("labels", &self.labels)?;
             s.serialize_field("body", &self.body)?;
+            serialize_comment(&mut s, self.comment.as_deref())?;
             s.end()
         }
     }
 }
 
+// The comment is omitted if it is `None` so that the serialized form of uncommented structures
+// does not change.
+fn serialize_comment<S>(s: &mut S, comment: Option<&str>) -> Result<(), S::Error>
+where
+    S: SerializeStruct,
+{
+    match comment {
+        Some(comment) => s.serialize_field("comment", comment),
+        None => s.skip_field("comment"),
+    }
+}
+
 impl ser::Serialize for Structure {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -793,11 +809,16 @@ impl ser::Serializer for BlockSerializer {
 
     serialize_unsupported! {
         bool i8 i16 i32 i64 u8 u16 u32 u64 f32 f64
-        char str bytes none unit unit_struct unit_variant
+        char str bytes unit unit_struct
     }
     serialize_self! { some }
     forward_to_serialize_seq! { tuple tuple_struct }
 
+    fn serialize_none(self) -> Result<Self::Ok> {
+        // A `None` value does not produce any block.
+        Ok(Structures::Multiple(Vec::new()))
+    }
+
     fn serialize_newtype_struct<T>(self, name: &'static str, value: &T) -> Result<Self::Ok>
     where
         T: ?Sized + ser::Serialize,
@@ -809,6 +830,20 @@ impl ser::Serializer for BlockSerializer {
         }
     }
 
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok> {
+        let variant = Identifier::new(variant)?;
+
+        Ok(Block::builder(self.ident)
+            .add_block(Block::builder(variant).build())
+            .build()
+            .into())
+    }
+
     fn serialize_newtype_variant<T>(
         self,
         _name: &'static str,
@@ -821,8 +856,14 @@ impl ser::Serializer for BlockSerializer {
     {
         let variant = Identifier::new(variant)?;
 
+        let structures = match value.serialize(BlockSerializer::new(variant.clone())) {
+            Ok(structures) => structures,
+            // Values which cannot be serialized as blocks, e.g. scalars, become attributes.
+            Err(_) => value.serialize(StructureSerializer::new(variant))?,
+        };
+
         Ok(Block::builder(self.ident)
-            .add_structures(value.serialize(StructureSerializer::new(variant))?)
+            .add_structures(structures)
             .build()
             .into())
     }
@@ -1097,11 +1138,16 @@ impl ser::Serializer for LabeledBlockSerializer {
 
     serialize_unsupported! {
         bool i8 i16 i32 i64 u8 u16 u32 u64 f32 f64
-        char str bytes none unit unit_struct unit_variant
+        char str bytes unit unit_struct unit_variant
     }
     serialize_self! { some newtype_struct }
     forward_to_serialize_seq! { tuple tuple_struct }
 
+    fn serialize_none(self) -> Result<Self::Ok> {
+        // A `None` value does not produce any block.
+        Ok(Structures::Multiple(Vec::new()))
+    }
+
     fn serialize_newtype_variant<T>(
         self,
         _name: &'static str,
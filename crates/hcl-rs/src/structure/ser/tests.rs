@@ -64,3 +64,25 @@ fn builtin() {
             .into(),
     );
 }
+
+#[test]
+fn comments() {
+    let attr = Attribute::new("foo", "bar");
+    let value = serde_json::to_value(&attr).unwrap();
+    assert_eq!(value, serde_json::json!({ "key": "foo", "expr": "bar" }));
+
+    let attr = attr.with_comment("a comment");
+    let value = serde_json::to_value(&attr).unwrap();
+    assert_eq!(
+        value,
+        serde_json::json!({ "key": "foo", "expr": "bar", "comment": "a comment" })
+    );
+
+    let block = Block::builder("foo").comment("a comment").build();
+    let value = serde_json::to_value(&block).unwrap();
+    assert_eq!(
+        value,
+        serde_json::json!({ "identifier": "foo", "labels": [], "body": [], "comment": "a comment" })
+    );
+    assert_eq!(serde_json::from_value::<Block>(value).unwrap(), block);
+}
@@ -1,6 +1,6 @@
-use super::{Block, Body};
-use crate::expr::{Heredoc, HeredocStripMode, RawExpression, TemplateExpr};
-use crate::{value, Identifier, Value};
+use super::{Attribute, Block, Body, Structure};
+use crate::expr::{Expression, Heredoc, HeredocStripMode, ObjectKey, RawExpression, TemplateExpr};
+use crate::{value, Identifier, Number, Value};
 use pretty_assertions::assert_eq;
 
 #[test]
@@ -61,3 +61,541 @@ fn body_into_value() {
 
     assert_eq!(Value::from(body), expected);
 }
+
+#[test]
+fn body_try_into_value() {
+    let body = Body::from(Block::builder("a").add_block(Block::new("b")).build());
+
+    assert_eq!(
+        body.clone().try_into_value(2).unwrap(),
+        value!({ a = { b = {} } })
+    );
+    assert_eq!(
+        body.try_into_value(1).unwrap_err().to_string(),
+        "block nesting depth of 2 exceeds the maximum of 1"
+    );
+}
+
+#[test]
+fn deeply_nested_body_try_into_value() {
+    let body = (0..1000).fold(Body::default(), |body, _| {
+        Body::from(
+            Block::builder("nested")
+                .add_label("label")
+                .add_structures(body)
+                .build(),
+        )
+    });
+
+    assert_eq!(
+        body.try_into_value(128).unwrap_err().to_string(),
+        "block nesting depth of 1000 exceeds the maximum of 128"
+    );
+}
+
+#[test]
+fn attribute_accessors() {
+    let attr = Attribute::new("foo", "bar");
+    assert_eq!(attr.as_str(), Some("bar"));
+    assert_eq!(attr.as_bool(), None);
+
+    let attr = Attribute::new("foo", -1);
+    assert_eq!(attr.as_number(), Some(&Number::from(-1)));
+    assert_eq!(attr.as_i64(), Some(-1));
+    assert_eq!(attr.as_str(), None);
+
+    let attr = Attribute::new("foo", true);
+    assert_eq!(attr.as_bool(), Some(true));
+    assert_eq!(attr.as_object(), None);
+
+    let attr = Attribute::new("foo", Expression::from_iter([("bar", 1)]));
+    let object = attr.as_object().unwrap();
+    assert_eq!(
+        object.get(&ObjectKey::from("bar")),
+        Some(&Expression::from(1))
+    );
+}
+
+#[test]
+fn body_len() {
+    let mut body = Body::default();
+    assert_eq!(body.len(), 0);
+    assert!(body.is_empty());
+
+    body.0.push(Attribute::new("a", 1).into());
+    body.0
+        .push(Block::builder("b").add_attribute(("c", 2)).build().into());
+    assert_eq!(body.len(), 2);
+    assert!(!body.is_empty());
+}
+
+#[test]
+fn body_get() {
+    let mut body = Body::builder()
+        .add_attribute(("version", "1.0.0"))
+        .add_block(
+            Block::builder("b")
+                .add_attribute(("version", "2.0.0"))
+                .build(),
+        )
+        .build();
+
+    assert_eq!(
+        body.get(0),
+        Some(&Attribute::new("version", "1.0.0").into())
+    );
+    assert!(body.get(1).unwrap().is_block());
+    assert_eq!(body.get(2), None);
+    assert_eq!(body.get_mut(2), None);
+
+    for structure in &mut body {
+        match structure {
+            Structure::Attribute(attr) => attr.expr = "1.1.0".into(),
+            Structure::Block(block) => {
+                let attr = block.body.get_mut(0).unwrap().as_attribute_mut().unwrap();
+                attr.expr = "2.1.0".into();
+            }
+        }
+    }
+
+    let expected = Body::builder()
+        .add_attribute(("version", "1.1.0"))
+        .add_block(
+            Block::builder("b")
+                .add_attribute(("version", "2.1.0"))
+                .build(),
+        )
+        .build();
+
+    assert_eq!(body, expected);
+}
+
+#[test]
+fn block_lookup() {
+    let body = Body::builder()
+        .add_attribute(("resource", 1))
+        .add_block(
+            Block::builder("resource")
+                .add_label("aws_s3_bucket")
+                .add_label(Identifier::new("a").unwrap())
+                .add_attribute(("id", 1))
+                .build(),
+        )
+        .add_block(
+            Block::builder("resource")
+                .add_labels(["aws_s3_bucket", "b"])
+                .add_attribute(("id", 2))
+                .build(),
+        )
+        .add_block(
+            Block::builder("data")
+                .add_labels(["aws_s3_bucket", "a"])
+                .build(),
+        )
+        .build();
+
+    let ids: Vec<_> = body
+        .blocks_with_identifier("resource")
+        .map(|block| block.body().attributes().next().unwrap().expr().clone())
+        .collect();
+    assert_eq!(ids, [Expression::from(1), Expression::from(2)]);
+
+    let block = body.get_block("resource", &["aws_s3_bucket", "a"]).unwrap();
+    assert!(block.has_label("a"));
+    assert!(block.has_label("aws_s3_bucket"));
+    assert!(!block.has_label("b"));
+    assert_eq!(block.body().get(0), Some(&Attribute::new("id", 1).into()));
+
+    assert_eq!(
+        body.get_block("data", &["aws_s3_bucket", "a"]),
+        body.blocks().nth(2)
+    );
+    assert_eq!(body.get_block("resource", &["aws_s3_bucket"]), None);
+    assert_eq!(
+        body.get_block("resource", &["aws_s3_bucket", "a", "x"]),
+        None
+    );
+    assert_eq!(body.get_block("resource", &["a", "aws_s3_bucket"]), None);
+}
+
+#[test]
+fn attribute_lookup() {
+    let mut body = Body::builder()
+        .add_attribute(("a", 1))
+        .add_block(Block::builder("b").add_attribute(("b", 2)).build())
+        .add_attribute(("a", 3))
+        .build();
+
+    assert_eq!(body.get_attribute("a"), Some(&Attribute::new("a", 1)));
+    assert_eq!(body.get_attribute("b"), None);
+    assert_eq!(
+        body.get_attributes("a").collect::<Vec<_>>(),
+        [&Attribute::new("a", 1), &Attribute::new("a", 3)]
+    );
+    assert_eq!(body.get_attributes("c").count(), 0);
+
+    body.get_attribute_mut("a").unwrap().expr = Expression::from(10);
+    assert!(body.get_attribute_mut("c").is_none());
+    assert_eq!(
+        body.get_attributes("a").collect::<Vec<_>>(),
+        [&Attribute::new("a", 10), &Attribute::new("a", 3)]
+    );
+}
+
+#[test]
+fn body_merge() {
+    let mut body = crate::body!({
+        a = 1
+        b = [1, 2]
+        block "x" {
+            c = 1
+            nested {
+                d = 1
+            }
+        }
+        block "y" {
+            c = 2
+        }
+    });
+
+    body.merge(crate::body!({
+        b = [3]
+        e = true
+        block "x" {
+            c = 10
+            nested {
+                f = 2
+            }
+            other {
+                g = 3
+            }
+        }
+        block "z" {
+            c = 3
+        }
+    }));
+
+    let expected = crate::body!({
+        a = 1
+        b = [3]
+        block "x" {
+            c = 10
+            nested {
+                d = 1
+                f = 2
+            }
+            other {
+                g = 3
+            }
+        }
+        block "y" {
+            c = 2
+        }
+        e = true
+        block "z" {
+            c = 3
+        }
+    });
+
+    assert_eq!(body, expected);
+
+    // Labels match regardless of their variant.
+    let mut body = Body::from(
+        Block::builder("block")
+            .add_label(Identifier::new("x").unwrap())
+            .add_attribute(("a", 1))
+            .build(),
+    );
+    body.merge(Body::from(
+        Block::builder("block")
+            .add_label("x")
+            .add_attribute(("a", 2))
+            .build(),
+    ));
+    assert_eq!(body.len(), 1);
+    assert_eq!(
+        body.get_block("block", &["x"]).unwrap().body(),
+        &Body::from(Attribute::new("a", 2))
+    );
+}
+
+#[test]
+fn body_merge_repeated_structures() {
+    // Repeated structures in `other` are not merged into each other.
+    let mut body = Body::default();
+    body.merge(crate::body!({
+        ingress { from_port = 80 }
+        ingress { from_port = 443 }
+        a = 1
+        a = 2
+    }));
+    assert_eq!(
+        body,
+        crate::body!({
+            ingress { from_port = 80 }
+            ingress { from_port = 443 }
+            a = 1
+            a = 2
+        })
+    );
+
+    // Repeated structures are matched with the structures in `self` in order, each of them at
+    // most once. Further occurrences are appended.
+    let mut body = crate::body!({
+        ingress { from_port = 22 }
+        egress { to_port = 0 }
+        ingress { from_port = 8080 }
+        a = 0
+    });
+    body.merge(crate::body!({
+        ingress { to_port = 22 }
+        ingress { to_port = 8080 }
+        ingress { from_port = 443 }
+        a = 1
+        a = 2
+    }));
+    assert_eq!(
+        body,
+        crate::body!({
+            ingress {
+                from_port = 22
+                to_port = 22
+            }
+            egress { to_port = 0 }
+            ingress {
+                from_port = 8080
+                to_port = 8080
+            }
+            a = 1
+            ingress { from_port = 443 }
+            a = 2
+        })
+    );
+}
+
+#[test]
+fn single_structure_into_body() {
+    let attr = Attribute::new("foo", "bar");
+    let block = Block::builder("block").add_attribute(("baz", 1)).build();
+
+    let body: Body = attr.clone().into();
+    assert_eq!(body, Body(vec![Structure::Attribute(attr.clone())]));
+    assert_eq!(crate::to_string(&body).unwrap(), "foo = \"bar\"\n");
+
+    let body: Body = block.clone().into();
+    assert_eq!(body, Body(vec![Structure::Block(block.clone())]));
+    assert_eq!(crate::to_string(&body).unwrap(), "block {\n  baz = 1\n}\n");
+
+    let body: Body = Structure::Attribute(attr.clone()).into();
+    assert_eq!(body, Body::from(attr));
+}
+
+#[test]
+fn accept_mut() {
+    use super::StructureVisitor;
+
+    struct Rename<'a>(&'a str, &'a str);
+
+    impl StructureVisitor for Rename<'_> {
+        fn visit_attribute_mut(&mut self, attr: &mut Attribute) {
+            if attr.key.as_str() == self.0 {
+                attr.key = Identifier::new(self.1).unwrap();
+            }
+        }
+    }
+
+    let mut body = crate::body!({
+        old = 1
+        outer {
+            old = 2
+            inner "label" {
+                old = 3
+                other = 4
+            }
+        }
+    });
+
+    body.accept_mut(&mut Rename("old", "new"));
+
+    let expected = crate::body!({
+        new = 1
+        outer {
+            new = 2
+            inner "label" {
+                new = 3
+                other = 4
+            }
+        }
+    });
+
+    assert_eq!(body, expected);
+}
+
+#[test]
+fn expression_try_into_value() {
+    fn expr(input: &str) -> Expression {
+        let body = crate::parse(&format!("expr = {input}\n")).unwrap();
+        body.into_attributes().next().unwrap().expr
+    }
+
+    let literal = expr(
+        r#"{ a = [1, true, null, ("str")], "b" = "x", 1 = { c = <<EOT
+heredoc
+EOT
+} }"#,
+    );
+
+    assert_eq!(
+        literal.try_into_value().unwrap(),
+        value!({
+            a = [1, true, null, "str"]
+            b = "x"
+            "1" = { c = "heredoc\n" }
+        })
+    );
+
+    let value = value!({ foo = [1, "two", { three = 3.5 }] });
+    assert_eq!(
+        Expression::from(value.clone()).try_into_value().unwrap(),
+        value
+    );
+
+    for (input, offending) in [
+        ("var.foo", "var.foo"),
+        ("[1, a + 1]", "a + 1"),
+        (r#"{ foo = "${bar}" }"#, r#""${bar}""#),
+        ("{ (k) = 1 }", "k"),
+        ("f(1)", "f(1)"),
+    ] {
+        let err = expr(input).try_into_value().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("expression `{offending}` is not a literal value")
+        );
+    }
+}
+
+#[test]
+fn validate() {
+    use crate::expr::{FuncCall, Traversal, Variable};
+
+    fn errors(body: Body) -> Vec<String> {
+        body.validate()
+            .unwrap_err()
+            .into_iter()
+            .map(|err| err.to_string())
+            .collect()
+    }
+
+    let valid = crate::parse(
+        r#"
+        resource "aws_s3_bucket" bucket {
+          name = var.name
+          tags = { for k, v in local.tags : k => upper(v) if v != "" }
+          script = <<EOT
+          EOT is only a delimiter on a line of its own
+        EOT
+        }
+        "#,
+    )
+    .unwrap();
+
+    assert!(valid.validate().is_ok());
+
+    assert_eq!(
+        errors(
+            Body::builder()
+                .add_attribute((Identifier::unchecked(""), 1))
+                .build()
+        ),
+        ["empty attribute key"]
+    );
+
+    assert_eq!(
+        errors(
+            Body::builder()
+                .add_block(
+                    Block::builder(Identifier::unchecked("1block"))
+                        .add_label(Identifier::unchecked("bad label"))
+                        .add_label("string\nlabel")
+                        .build()
+                )
+                .build()
+        ),
+        [
+            r#"1block.bad label."string\nlabel": invalid block identifier `1block`"#,
+            r#"1block.bad label."string\nlabel": invalid block label `bad label`"#,
+        ]
+    );
+
+    assert_eq!(
+        errors(
+            Body::builder()
+                .add_block(
+                    Block::builder("block")
+                        .add_attribute(("var", Variable::unchecked("a b")))
+                        .add_attribute((
+                            "traversal",
+                            Traversal::builder(Variable::unchecked("var"))
+                                .attr(Identifier::unchecked("x.y"))
+                                .build()
+                        ))
+                        .add_attribute(("func", FuncCall::new(Identifier::unchecked("f()"))))
+                        .add_attribute((
+                            "object",
+                            Expression::from_iter([(
+                                ObjectKey::Identifier(Identifier::unchecked("k k")),
+                                Expression::Null
+                            )])
+                        ))
+                        .build()
+                )
+                .build()
+        ),
+        [
+            "block.var: invalid variable name `a b`",
+            "block.traversal: invalid attribute name `x.y`",
+            "block.func: invalid function name `f()`",
+            "block.object: invalid object key `k k`",
+        ]
+    );
+
+    assert_eq!(
+        errors(
+            Body::builder()
+                .add_attribute((
+                    "heredoc",
+                    TemplateExpr::Heredoc(Heredoc::new(
+                        Identifier::unchecked("EOT"),
+                        "foo\n  EOT\nbar\n"
+                    ))
+                ))
+                .build()
+        ),
+        ["heredoc: heredoc template contains its delimiter `EOT` on a line of its own"]
+    );
+}
+
+#[test]
+fn display() {
+    let attr = Attribute::new("foo", "bar");
+    let block = Block::builder("block")
+        .add_label("label")
+        .add_attribute(attr.clone())
+        .build();
+    let body = Body::builder()
+        .add_attribute(attr.clone())
+        .add_block(block.clone())
+        .build();
+
+    assert_eq!(attr.to_string(), "foo = \"bar\"\n");
+    assert_eq!(block.to_string(), "block \"label\" {\n  foo = \"bar\"\n}\n");
+    assert_eq!(
+        Structure::from(block).to_string(),
+        "block \"label\" {\n  foo = \"bar\"\n}\n"
+    );
+    assert_eq!(
+        format!("{body}"),
+        "foo = \"bar\"\n\nblock \"label\" {\n  foo = \"bar\"\n}\n"
+    );
+}
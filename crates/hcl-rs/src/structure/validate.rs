@@ -0,0 +1,212 @@
+//! Structural sanity checks for HCL bodies.
+
+use super::{Attribute, Block, BlockLabel, Body, Structure};
+use crate::expr::{Expression, Heredoc, ObjectKey, Operation, TemplateExpr, TraversalOperator};
+use crate::util::is_ident;
+use crate::{Error, Identifier};
+
+impl Body {
+    /// Checks that the `Body` can be formatted as HCL which parses back into an equivalent
+    /// `Body`.
+    ///
+    /// Most structural invariants are enforced by the types of this crate, but some of them can
+    /// be bypassed, e.g. via [`Identifier::unchecked`]. This method walks all structures and the
+    /// expressions they contain and checks that:
+    ///
+    /// - attribute keys, block identifiers and identifier block labels are valid identifiers
+    /// - identifiers used within expressions (variables, attribute access, function names, `for`
+    ///   expression variables and object keys) are valid
+    /// - heredoc delimiters are valid identifiers and do not appear on a line of their own within
+    ///   the heredoc template, which would terminate the heredoc early
+    ///
+    /// Numbers are always finite and string block labels are escaped when formatted, so these do
+    /// not need to be validated. [Raw expressions][crate::expr::RawExpression] are emitted
+    /// verbatim and are not validated either.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use hcl::{Block, Body, Identifier};
+    ///
+    /// let body = Body::builder()
+    ///     .add_block(
+    ///         Block::builder("resource")
+    ///             .add_label("bucket")
+    ///             .add_attribute((Identifier::unchecked("bucket name"), "foo"))
+    ///             .build(),
+    ///     )
+    ///     .build();
+    ///
+    /// let errors = body.validate().unwrap_err();
+    ///
+    /// assert_eq!(errors.len(), 1);
+    /// assert_eq!(
+    ///     errors[0].to_string(),
+    ///     r#"resource."bucket".bucket name: invalid attribute key `bucket name`"#
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns all problems that were found. Each error message is prefixed with the path of the
+    /// offending structure, consisting of the attribute keys, block identifiers and quoted block
+    /// labels separated by dots.
+    pub fn validate(&self) -> Result<(), Vec<Error>> {
+        let mut validator = Validator::default();
+        validator.body(self);
+
+        if validator.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(validator.errors)
+        }
+    }
+}
+
+#[derive(Default)]
+struct Validator {
+    path: Vec<String>,
+    errors: Vec<Error>,
+}
+
+impl Validator {
+    fn error(&mut self, msg: String) {
+        let path = self.path.join(".");
+
+        if path.is_empty() {
+            self.errors.push(Error::new(msg));
+        } else {
+            self.errors.push(Error::new(format!("{path}: {msg}")));
+        }
+    }
+
+    fn ident(&mut self, kind: &str, ident: &Identifier) {
+        if ident.is_empty() {
+            self.error(format!("empty {kind}"));
+        } else if !is_ident(ident) {
+            self.error(format!("invalid {kind} `{ident}`"));
+        }
+    }
+
+    fn body(&mut self, body: &Body) {
+        for structure in body {
+            match structure {
+                Structure::Attribute(attr) => self.attribute(attr),
+                Structure::Block(block) => self.block(block),
+            }
+        }
+    }
+
+    fn attribute(&mut self, attr: &Attribute) {
+        self.path.push(attr.key.to_string());
+        self.ident("attribute key", &attr.key);
+        self.expr(&attr.expr);
+        self.path.pop();
+    }
+
+    fn block(&mut self, block: &Block) {
+        let len = self.path.len();
+        self.path.push(block.identifier.to_string());
+        self.path
+            .extend(block.labels.iter().map(|label| match label {
+                BlockLabel::Identifier(ident) => ident.to_string(),
+                BlockLabel::String(string) => format!("{string:?}"),
+            }));
+
+        self.ident("block identifier", &block.identifier);
+
+        for label in &block.labels {
+            if let BlockLabel::Identifier(ident) = label {
+                self.ident("block label", ident);
+            }
+        }
+
+        self.body(&block.body);
+        self.path.truncate(len);
+    }
+
+    fn expr(&mut self, expr: &Expression) {
+        match expr {
+            Expression::Array(array) => array.iter().for_each(|expr| self.expr(expr)),
+            Expression::Object(object) => {
+                for (key, value) in object {
+                    match key {
+                        ObjectKey::Identifier(ident) => self.ident("object key", ident),
+                        ObjectKey::Expression(expr) => self.expr(expr),
+                    }
+
+                    self.expr(value);
+                }
+            }
+            Expression::TemplateExpr(expr) => {
+                if let TemplateExpr::Heredoc(heredoc) = &**expr {
+                    self.heredoc(heredoc);
+                }
+            }
+            Expression::Variable(var) => self.ident("variable name", var),
+            Expression::Traversal(traversal) => {
+                self.expr(&traversal.expr);
+
+                for operator in &traversal.operators {
+                    match operator {
+                        TraversalOperator::GetAttr(ident) => self.ident("attribute name", ident),
+                        TraversalOperator::Index(expr) => self.expr(expr),
+                        _ => {}
+                    }
+                }
+            }
+            Expression::FuncCall(func_call) => {
+                self.ident("function name", &func_call.name);
+                func_call.args.iter().for_each(|expr| self.expr(expr));
+            }
+            Expression::Parenthesis(expr) => self.expr(expr),
+            Expression::Conditional(cond) => {
+                self.expr(&cond.cond_expr);
+                self.expr(&cond.true_expr);
+                self.expr(&cond.false_expr);
+            }
+            Expression::Operation(op) => match &**op {
+                Operation::Unary(op) => self.expr(&op.expr),
+                Operation::Binary(op) => {
+                    self.expr(&op.lhs_expr);
+                    self.expr(&op.rhs_expr);
+                }
+            },
+            Expression::ForExpr(for_expr) => {
+                if let Some(key_var) = &for_expr.key_var {
+                    self.ident("variable name", key_var);
+                }
+
+                self.ident("variable name", &for_expr.value_var);
+                self.expr(&for_expr.collection_expr);
+
+                if let Some(key_expr) = &for_expr.key_expr {
+                    self.expr(key_expr);
+                }
+
+                self.expr(&for_expr.value_expr);
+
+                if let Some(cond_expr) = &for_expr.cond_expr {
+                    self.expr(cond_expr);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn heredoc(&mut self, heredoc: &Heredoc) {
+        self.ident("heredoc delimiter", &heredoc.delimiter);
+
+        let delimiter = heredoc.delimiter.as_str();
+
+        if heredoc
+            .template
+            .lines()
+            .any(|line| line.trim_start_matches([' ', '\t']) == delimiter)
+        {
+            self.error(format!(
+                "heredoc template contains its delimiter `{delimiter}` on a line of its own"
+            ));
+        }
+    }
+}
@@ -0,0 +1,91 @@
+//! A visitor for mutating HCL structures.
+
+use super::{Attribute, Block, Body, Structure};
+
+/// A visitor which is driven recursively over the structures of a [`Body`] via
+/// [`Body::accept_mut`].
+///
+/// All methods have default implementations. The default implementation of
+/// [`visit_attribute_mut`][StructureVisitor::visit_attribute_mut] does nothing, while the default
+/// implementation of [`visit_block_mut`][StructureVisitor::visit_block_mut] descends into the
+/// block's body. Implementations overriding `visit_block_mut` need to call
+/// [`Body::accept_mut`] on the block body themselves if nested structures should be visited as
+/// well.
+///
+/// # Example
+///
+/// Rename the deprecated attribute `bucket_name` to `bucket` in all `resource` blocks:
+///
+/// ```
+/// use hcl::structure::StructureVisitor;
+/// use hcl::{Attribute, Block, Identifier};
+///
+/// #[derive(Default)]
+/// struct Rename {
+///     in_resource: bool,
+/// }
+///
+/// impl StructureVisitor for Rename {
+///     fn visit_attribute_mut(&mut self, attr: &mut Attribute) {
+///         if self.in_resource && attr.key.as_str() == "bucket_name" {
+///             attr.key = Identifier::unchecked("bucket");
+///         }
+///     }
+///
+///     fn visit_block_mut(&mut self, block: &mut Block) {
+///         let in_resource = self.in_resource;
+///         self.in_resource |= block.identifier() == "resource";
+///         block.body.accept_mut(self);
+///         self.in_resource = in_resource;
+///     }
+/// }
+///
+/// let mut body = hcl::body!({
+///     resource "aws_s3_bucket" "bucket" {
+///         bucket_name = "mybucket"
+///     }
+///     bucket_name = "unchanged"
+/// });
+///
+/// body.accept_mut(&mut Rename::default());
+///
+/// let expected = hcl::body!({
+///     resource "aws_s3_bucket" "bucket" {
+///         bucket = "mybucket"
+///     }
+///     bucket_name = "unchanged"
+/// });
+///
+/// assert_eq!(body, expected);
+/// ```
+pub trait StructureVisitor {
+    /// Visits an attribute. The attribute may be mutated or replaced.
+    #[allow(unused_variables)]
+    fn visit_attribute_mut(&mut self, attr: &mut Attribute) {}
+
+    /// Visits a block. The block may be mutated or replaced.
+    ///
+    /// The default implementation visits all structures in the block's body.
+    fn visit_block_mut(&mut self, block: &mut Block) {
+        block.body.accept_mut(self);
+    }
+}
+
+impl Body {
+    /// Drives the visitor over all structures in the `Body`.
+    ///
+    /// Attributes are passed to [`StructureVisitor::visit_attribute_mut`] and blocks are passed
+    /// to [`StructureVisitor::visit_block_mut`] in the order in which they appear in the body.
+    /// See the documentation of [`StructureVisitor`] for an example.
+    pub fn accept_mut<V>(&mut self, visitor: &mut V)
+    where
+        V: StructureVisitor + ?Sized,
+    {
+        for structure in self.iter_mut() {
+            match structure {
+                Structure::Attribute(attr) => visitor.visit_attribute_mut(attr),
+                Structure::Block(block) => visitor.visit_block_mut(block),
+            }
+        }
+    }
+}
@@ -34,17 +34,18 @@ pub fn dedent(s: &str) -> Cow<str> {
     // Strip the determined amount of leading whitespace off of each line.
     let mut dedented = String::with_capacity(s.len() - leading_ws * non_empty_lines);
 
-    for line in s.lines() {
-        if !line.is_empty() {
-            dedented.extend(line.chars().skip(leading_ws));
+    // Operate on logical lines but retain their original line endings, which can either be `\n`
+    // or `\r\n`.
+    for line in s.split_inclusive('\n') {
+        let content = line
+            .strip_suffix('\n')
+            .map_or(line, |line| line.strip_suffix('\r').unwrap_or(line));
+
+        if !content.is_empty() {
+            dedented.extend(content.chars().skip(leading_ws));
         }
 
-        dedented.push('\n');
-    }
-
-    if dedented.ends_with('\n') && !s.ends_with('\n') {
-        let new_len = dedented.len() - 1;
-        dedented.truncate(new_len);
+        dedented.push_str(&line[content.len()..]);
     }
 
     Cow::Owned(dedented)
@@ -126,6 +127,26 @@ pub fn try_unescape(s: &str) -> Cow<str> {
     }
 }
 
+/// Encodes `bytes` as a base64 string using the standard alphabet with padding.
+#[cfg(feature = "base64")]
+pub fn encode_base64(bytes: &[u8]) -> String {
+    use base64::Engine;
+    base64::engine::general_purpose::STANDARD.encode(bytes)
+}
+
+/// Decodes a base64 string that was encoded using the standard alphabet with padding.
+///
+/// ## Errors
+///
+/// Returns an error if `s` is not valid base64.
+#[cfg(feature = "base64")]
+pub fn decode_base64(s: &str) -> Result<Vec<u8>> {
+    use base64::Engine;
+    base64::engine::general_purpose::STANDARD
+        .decode(s)
+        .map_err(Error::new)
+}
+
 /// Scan `s` for sequences that introduce a template interpolation or directive. Returns `true`
 /// once it found one of these start markers, `false` otherwise.
 ///
@@ -202,4 +223,12 @@ mod tests {
         ));
         assert!(!is_templated("escaped interpolation $${a}"));
     }
+
+    #[test]
+    fn test_dedent() {
+        assert_eq!(dedent("  foo\n    bar\n"), "foo\n  bar\n");
+        assert_eq!(dedent("  foo\n\n    bar"), "foo\n\n  bar");
+        assert_eq!(dedent("  foo\r\n\r\n    bar\r\n"), "foo\r\n\r\n  bar\r\n");
+        assert_eq!(dedent("foo\r\n  bar"), "foo\r\n  bar");
+    }
 }
@@ -18,7 +18,7 @@ impl_from_integer!(u8, u16, u32, u64, usize);
 
 impl From<f32> for Value {
     fn from(f: f32) -> Self {
-        From::from(f as f64)
+        Number::from_f32(f).map_or(Value::Null, Value::Number)
     }
 }
 
@@ -3,17 +3,35 @@
 pub(crate) mod de;
 mod from;
 mod ser;
+#[cfg(test)]
+mod tests;
 
 use self::ser::ValueSerializer;
-use crate::{format, Number, Result};
+use crate::{format, Error, Number, Result};
+use serde::de::{Error as _, Unexpected};
 use serde::ser::Serialize;
+use std::borrow::Cow;
+use std::cmp::Ordering;
 use std::fmt;
+use std::str::FromStr;
 
 /// The map type used for HCL objects.
+///
+/// Object keys retain their insertion order, so parsing and serializing an object preserves the
+/// order of its keys in the source.
 pub type Map<K, V> = indexmap::IndexMap<K, V>;
 
 /// Represents any valid HCL value.
-#[derive(Debug, PartialEq, Eq, Clone)]
+///
+/// The alternate `Debug` representation (`{:#?}`) renders the value as formatted HCL instead of
+/// the nested enum structure, which makes failing assertions on large values easier to read.
+///
+/// Values have a total order, which allows sorting them or using them as keys of a `BTreeMap`.
+/// Values of different types are ordered by their type: `Null < Bool < Number < String < Array <
+/// Object`. Values of the same type are compared by their contents, where arrays are compared
+/// lexicographically and objects are compared by their entries sorted by key, so that the
+/// insertion order of object keys does not influence the ordering.
+#[derive(PartialEq, Eq, Clone)]
 pub enum Value {
     /// Represents a HCL null value.
     Null,
@@ -125,6 +143,132 @@ impl Value {
         self.as_number().and_then(Number::as_u64)
     }
 
+    /// Like [`Value::as_array`], but returns a descriptive error instead of `None`.
+    ///
+    /// The `try_as_*` methods are useful for loading configuration with `?`:
+    ///
+    /// ```
+    /// let value = hcl::value!({ ports = [80, "443"] });
+    ///
+    /// let ports = value.try_as_object()?["ports"].try_as_array()?;
+    /// assert_eq!(ports[0].try_as_u64()?, 80);
+    ///
+    /// let err = ports[1].try_as_u64().unwrap_err();
+    /// assert_eq!(err.to_string(), "invalid type: string \"443\", expected a number");
+    /// # Ok::<(), hcl::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `Value` is not an Array.
+    pub fn try_as_array(&self) -> Result<&Vec<Value>> {
+        self.as_array().ok_or_else(|| self.invalid_type("an array"))
+    }
+
+    /// Like [`Value::as_array_mut`], but returns a descriptive error instead of `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `Value` is not an Array.
+    pub fn try_as_array_mut(&mut self) -> Result<&mut Vec<Value>> {
+        match self {
+            Self::Array(value) => Ok(value),
+            other => Err(other.invalid_type("an array")),
+        }
+    }
+
+    /// Like [`Value::as_bool`], but returns a descriptive error instead of `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `Value` is not a Boolean.
+    pub fn try_as_bool(&self) -> Result<bool> {
+        self.as_bool().ok_or_else(|| self.invalid_type("a boolean"))
+    }
+
+    /// Like [`Value::as_f64`], but returns a descriptive error instead of `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `Value` is not a Number or if it cannot be represented as f64.
+    pub fn try_as_f64(&self) -> Result<f64> {
+        let num = self.try_as_number()?;
+        num.as_f64()
+            .ok_or_else(|| Error::invalid_value(num.unexpected(), &"an f64"))
+    }
+
+    /// Like [`Value::as_i64`], but returns a descriptive error instead of `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `Value` is not a Number or if it cannot be represented as i64.
+    pub fn try_as_i64(&self) -> Result<i64> {
+        let num = self.try_as_number()?;
+        num.as_i64()
+            .ok_or_else(|| Error::invalid_value(num.unexpected(), &"an i64"))
+    }
+
+    /// Like [`Value::as_null`], but returns a descriptive error instead of `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `Value` is not a Null.
+    pub fn try_as_null(&self) -> Result<()> {
+        self.as_null().ok_or_else(|| self.invalid_type("null"))
+    }
+
+    /// Like [`Value::as_number`], but returns a descriptive error instead of `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `Value` is not a Number.
+    pub fn try_as_number(&self) -> Result<&Number> {
+        self.as_number()
+            .ok_or_else(|| self.invalid_type("a number"))
+    }
+
+    /// Like [`Value::as_object`], but returns a descriptive error instead of `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `Value` is not an Object.
+    pub fn try_as_object(&self) -> Result<&Map<String, Value>> {
+        self.as_object()
+            .ok_or_else(|| self.invalid_type("an object"))
+    }
+
+    /// Like [`Value::as_object_mut`], but returns a descriptive error instead of `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `Value` is not an Object.
+    pub fn try_as_object_mut(&mut self) -> Result<&mut Map<String, Value>> {
+        match self {
+            Self::Object(value) => Ok(value),
+            other => Err(other.invalid_type("an object")),
+        }
+    }
+
+    /// Like [`Value::as_str`], but returns a descriptive error instead of `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `Value` is not a String.
+    pub fn try_as_str(&self) -> Result<&str> {
+        self.as_str().ok_or_else(|| self.invalid_type("a string"))
+    }
+
+    /// Like [`Value::as_u64`], but returns a descriptive error instead of `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `Value` is not a Number or if it cannot be represented as u64.
+    pub fn try_as_u64(&self) -> Result<u64> {
+        let num = self.try_as_number()?;
+        num.as_u64()
+            .ok_or_else(|| Error::invalid_value(num.unexpected(), &"a u64"))
+    }
+
     /// Returns true if the `Value` is an Array. Returns false otherwise.
     ///
     /// For any Value on which `is_array` returns true, `as_array` and
@@ -197,10 +341,514 @@ impl Value {
         self.as_number().map_or(false, Number::is_u64)
     }
 
+    /// If the `Value` is an Object, retains only the entries for which the predicate returns
+    /// `true`. Does nothing otherwise.
+    ///
+    /// ```
+    /// use hcl::Value;
+    ///
+    /// let mut value = Value::from_iter([("user", "admin"), ("password", "secret")]);
+    /// value.retain_object(|key, _| key != "password");
+    ///
+    /// assert_eq!(value, Value::from_iter([("user", "admin")]));
+    /// ```
+    pub fn retain_object<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&str, &Value) -> bool,
+    {
+        if let Value::Object(object) = self {
+            object.retain(|key, value| f(key, value));
+        }
+    }
+
+    /// If the `Value` is an Array, retains only the elements for which the predicate returns
+    /// `true`. Does nothing otherwise.
+    ///
+    /// ```
+    /// use hcl::Value;
+    ///
+    /// let mut value = Value::from_iter([1, 2, 3, 4]);
+    /// value.retain_array(|value| value.as_u64().map_or(false, |n| n % 2 == 0));
+    ///
+    /// assert_eq!(value, Value::from_iter([2, 4]));
+    /// ```
+    pub fn retain_array<F>(&mut self, f: F)
+    where
+        F: FnMut(&Value) -> bool,
+    {
+        if let Value::Array(array) = self {
+            array.retain(f);
+        }
+    }
+
+    /// If the `Value` is an Array, returns a reference to the element at `index`. Negative
+    /// indices count backwards from the end of the array, so `-1` refers to the last element.
+    ///
+    /// Returns `None` if the `Value` is not an Array or if `index` is out of range.
+    ///
+    /// ```
+    /// use hcl::Value;
+    ///
+    /// let value = Value::from_iter(["v1", "v2", "v3"]);
+    ///
+    /// assert_eq!(value.get_index(0), Some(&Value::from("v1")));
+    /// assert_eq!(value.get_index(-1), Some(&Value::from("v3")));
+    /// assert_eq!(value.get_index(3), None);
+    /// ```
+    pub fn get_index(&self, index: isize) -> Option<&Value> {
+        let array = self.as_array()?;
+
+        let index = if index < 0 {
+            array.len().checked_sub(index.unsigned_abs())?
+        } else {
+            index.unsigned_abs()
+        };
+
+        array.get(index)
+    }
+
+    /// Looks up a value by a JSON Pointer as defined in [RFC 6901].
+    ///
+    /// A pointer is a string of reference tokens, each prefixed by `/`. Tokens refer to object
+    /// keys or array indices, and `~1` and `~0` within a token are unescaped to `/` and `~`
+    /// respectively. The empty pointer `""` refers to the whole `Value`.
+    ///
+    /// Returns `None` if the pointer is invalid or if no value exists at its location.
+    ///
+    /// [RFC 6901]: https://tools.ietf.org/html/rfc6901
+    ///
+    /// ```
+    /// let value = hcl::value!({ resource = { bucket = { tags = ["a", "b"] } } });
+    ///
+    /// assert_eq!(value.pointer("/resource/bucket/tags/1"), Some(&hcl::Value::from("b")));
+    /// assert_eq!(value.pointer("/resource/queue"), None);
+    /// ```
+    pub fn pointer(&self, pointer: &str) -> Option<&Value> {
+        if pointer.is_empty() {
+            return Some(self);
+        }
+
+        pointer
+            .strip_prefix('/')?
+            .split('/')
+            .map(unescape_pointer_token)
+            .try_fold(self, |target, token| match target {
+                Value::Object(object) => object.get(&*token),
+                Value::Array(array) => parse_pointer_index(&token).and_then(|i| array.get(i)),
+                _ => None,
+            })
+    }
+
+    /// Looks up a value by a JSON Pointer as defined in [RFC 6901] and returns a mutable
+    /// reference to it.
+    ///
+    /// See [`Value::pointer`] for the pointer syntax. Returns `None` if the pointer is invalid or
+    /// if no value exists at its location. Use [`Value::pointer_set`] to insert values at
+    /// locations which do not exist yet.
+    ///
+    /// [RFC 6901]: https://tools.ietf.org/html/rfc6901
+    ///
+    /// ```
+    /// let mut value = hcl::value!({ resource = { bucket = { tags = ["a", "b"] } } });
+    ///
+    /// if let Some(tag) = value.pointer_mut("/resource/bucket/tags/1") {
+    ///     *tag = "c".into();
+    /// }
+    ///
+    /// assert_eq!(value, hcl::value!({ resource = { bucket = { tags = ["a", "c"] } } }));
+    /// assert_eq!(value.pointer_mut("/resource/queue"), None);
+    /// ```
+    pub fn pointer_mut(&mut self, pointer: &str) -> Option<&mut Value> {
+        if pointer.is_empty() {
+            return Some(self);
+        }
+
+        pointer
+            .strip_prefix('/')?
+            .split('/')
+            .map(unescape_pointer_token)
+            .try_fold(self, |target, token| match target {
+                Value::Object(object) => object.get_mut(&*token),
+                Value::Array(array) => parse_pointer_index(&token).and_then(|i| array.get_mut(i)),
+                _ => None,
+            })
+    }
+
+    /// Sets the value at the location referenced by a JSON Pointer as defined in [RFC 6901],
+    /// creating missing intermediate objects along the way.
+    ///
+    /// See [`Value::pointer`] for the pointer syntax. Reference tokens are resolved as follows:
+    ///
+    /// - Within objects, a missing key is inserted. If it is not the last token, an empty object
+    ///   is inserted and traversal continues in it.
+    /// - Within arrays, the token must be an index. An index equal to the array length, or the
+    ///   special token `-`, appends a new element (an empty object for intermediate tokens). Any
+    ///   other index beyond the end of the array is an error; arrays are never padded.
+    /// - Scalar values cannot be traversed and are never replaced implicitly, so a token that
+    ///   would descend into a scalar is an error.
+    ///
+    /// The empty pointer `""` replaces the whole `Value`.
+    ///
+    /// [RFC 6901]: https://tools.ietf.org/html/rfc6901
+    ///
+    /// ```
+    /// let mut value = hcl::value!({ resource = { bucket = { acl = "private" } } });
+    ///
+    /// value.pointer_set("/resource/bucket/acl", "public-read".into())?;
+    /// value.pointer_set("/resource/bucket/tags/env", "prod".into())?;
+    ///
+    /// let expected = hcl::value!({
+    ///     resource = {
+    ///         bucket = {
+    ///             acl = "public-read"
+    ///             tags = { env = "prod" }
+    ///         }
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(value, expected);
+    /// assert!(value.pointer_set("/resource/bucket/acl/grant", "read".into()).is_err());
+    /// # Ok::<(), hcl::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pointer is neither empty nor starts with `/`, if a token refers
+    /// into a scalar value, or if an array index is invalid or out of range. The `Value` is left
+    /// unchanged in this case, except for any intermediate objects that were already created.
+    pub fn pointer_set(&mut self, pointer: &str, value: Value) -> Result<()> {
+        if pointer.is_empty() {
+            *self = value;
+            return Ok(());
+        }
+
+        let Some(tokens) = pointer.strip_prefix('/') else {
+            return Err(Error::new(format!(
+                "invalid pointer `{pointer}`: must be empty or start with `/`"
+            )));
+        };
+
+        let tokens: Vec<_> = tokens.split('/').map(unescape_pointer_token).collect();
+        let (last, parents) = tokens.split_last().unwrap();
+
+        let mut target = self;
+
+        for token in parents {
+            target = match target {
+                Value::Object(object) => object
+                    .entry(token.to_string())
+                    .or_insert_with(|| Value::Object(Map::new())),
+                Value::Array(array) => {
+                    let index = pointer_set_index(pointer, token, array.len())?;
+
+                    if index == array.len() {
+                        array.push(Value::Object(Map::new()));
+                    }
+
+                    &mut array[index]
+                }
+                _ => return Err(pointer_into_scalar(pointer, token)),
+            };
+        }
+
+        match target {
+            Value::Object(object) => {
+                object.insert(last.to_string(), value);
+            }
+            Value::Array(array) => {
+                let index = pointer_set_index(pointer, last, array.len())?;
+
+                if index == array.len() {
+                    array.push(value);
+                } else {
+                    array[index] = value;
+                }
+            }
+            _ => return Err(pointer_into_scalar(pointer, last)),
+        }
+
+        Ok(())
+    }
+
+    /// Consumes the `Value` and applies `f` to each scalar value (null, boolean, number or
+    /// string), including scalars nested arbitrarily deep inside arrays and objects.
+    ///
+    /// Arrays and objects are transformed in place, so their allocations are reused and the tree
+    /// does not need to be cloned upfront. The closure is never invoked with an array or object.
+    ///
+    /// ```
+    /// use hcl::Value;
+    ///
+    /// let value = hcl::value!({
+    ///     user = "admin"
+    ///     password = "secret"
+    ///     ports = [80, 443]
+    /// });
+    ///
+    /// let redacted = value.map(|value| match value {
+    ///     Value::String(_) => Value::from("<redacted>"),
+    ///     other => other,
+    /// });
+    ///
+    /// let expected = hcl::value!({
+    ///     user = "<redacted>"
+    ///     password = "<redacted>"
+    ///     ports = [80, 443]
+    /// });
+    ///
+    /// assert_eq!(redacted, expected);
+    /// ```
+    pub fn map<F>(mut self, mut f: F) -> Value
+    where
+        F: FnMut(Value) -> Value,
+    {
+        self.map_scalars(&mut f);
+        self
+    }
+
+    fn map_scalars<F>(&mut self, f: &mut F)
+    where
+        F: FnMut(Value) -> Value,
+    {
+        match self {
+            Value::Array(array) => {
+                for value in array {
+                    value.map_scalars(f);
+                }
+            }
+            Value::Object(object) => {
+                for value in object.values_mut() {
+                    value.map_scalars(f);
+                }
+            }
+            scalar => *scalar = f(scalar.take()),
+        }
+    }
+
+    /// Recursively sorts the keys of all objects contained in the `Value` in place.
+    ///
+    /// Objects preserve insertion order, so this can be used to normalize two semantically equal
+    /// values such that they produce identical output regardless of the order in which their
+    /// keys were inserted. The order of array elements is left untouched.
+    ///
+    /// ```
+    /// let mut value = hcl::value!({ b = { d = 1, c = 2 }, a = [3, 1, 2] });
+    /// value.sort_keys();
+    ///
+    /// let expected = "a = [\n  3,\n  1,\n  2\n]\n\
+    ///                 b = {\n  \"c\" = 2\n  \"d\" = 1\n}\n";
+    ///
+    /// assert_eq!(hcl::to_string(&value)?, expected);
+    /// # Ok::<(), hcl::Error>(())
+    /// ```
+    pub fn sort_keys(&mut self) {
+        match self {
+            Value::Array(array) => {
+                for value in array {
+                    value.sort_keys();
+                }
+            }
+            Value::Object(object) => {
+                object.sort_keys();
+
+                for value in object.values_mut() {
+                    value.sort_keys();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    #[cold]
+    fn unexpected(&self) -> Unexpected<'_> {
+        match self {
+            Value::Null => Unexpected::Unit,
+            Value::Bool(b) => Unexpected::Bool(*b),
+            Value::Number(num) => num.unexpected(),
+            Value::String(s) => Unexpected::Str(s),
+            Value::Array(_) => Unexpected::Seq,
+            Value::Object(_) => Unexpected::Map,
+        }
+    }
+
+    #[cold]
+    fn invalid_type(&self, expected: &str) -> Error {
+        Error::invalid_type(self.unexpected(), &expected)
+    }
+
     /// Takes the value out of the `Value`, leaving a `Null` in its place.
     pub fn take(&mut self) -> Value {
         std::mem::replace(self, Value::Null)
     }
+
+    /// Recursively merges `other` into `self` using [`MergeStrategy::Overwrite`].
+    ///
+    /// See [`Value::merge_with`] for details.
+    ///
+    /// ```
+    /// let mut value = hcl::value!({ a = 1, b = { c = 2, d = [1] } });
+    ///
+    /// value.merge(hcl::value!({ b = { d = [2], e = 3 } }));
+    ///
+    /// assert_eq!(value, hcl::value!({ a = 1, b = { c = 2, d = [2], e = 3 } }));
+    /// ```
+    pub fn merge(&mut self, other: Value) {
+        self.merge_with(other, MergeStrategy::Overwrite);
+    }
+
+    /// Merges `other` into `self` using the given [`MergeStrategy`].
+    ///
+    /// If both values are objects, the keys of `other` are merged into `self`: keys which only
+    /// exist in `other` are appended in their original order, keys which exist in both are
+    /// merged (or replaced if the strategy is [`MergeStrategy::Shallow`]) in place. In all other
+    /// cases, e.g. for scalars or values of different types, `other` replaces `self`, except for
+    /// two arrays which are concatenated if the strategy is [`MergeStrategy::AppendArrays`].
+    ///
+    /// ```
+    /// use hcl::value::MergeStrategy;
+    ///
+    /// let mut value = hcl::value!({ a = { b = [1], c = true } });
+    ///
+    /// value.merge_with(hcl::value!({ a = { b = [2] } }), MergeStrategy::AppendArrays);
+    /// assert_eq!(value, hcl::value!({ a = { b = [1, 2], c = true } }));
+    ///
+    /// value.merge_with(hcl::value!({ a = { b = [3] } }), MergeStrategy::Shallow);
+    /// assert_eq!(value, hcl::value!({ a = { b = [3] } }));
+    /// ```
+    pub fn merge_with(&mut self, other: Value, strategy: MergeStrategy) {
+        match (self, other) {
+            (Value::Object(object), Value::Object(other)) => {
+                for (key, value) in other {
+                    match object.get_mut(&key) {
+                        Some(existing) if strategy != MergeStrategy::Shallow => {
+                            existing.merge_with(value, strategy);
+                        }
+                        _ => {
+                            object.insert(key, value);
+                        }
+                    }
+                }
+            }
+            (Value::Array(array), Value::Array(other))
+                if strategy == MergeStrategy::AppendArrays =>
+            {
+                array.extend(other);
+            }
+            (value, other) => *value = other,
+        }
+    }
+}
+
+/// Controls how [`Value::merge_with`] handles values which exist on both sides.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MergeStrategy {
+    /// Objects are merged recursively, all other values (including arrays) are replaced by the
+    /// right-hand side. This is the default.
+    #[default]
+    Overwrite,
+    /// Like [`Overwrite`][MergeStrategy::Overwrite], but arrays on both sides are concatenated.
+    AppendArrays,
+    /// Only the top-level keys of objects are merged, values of keys which exist on both sides
+    /// are replaced by the right-hand side without merging them recursively.
+    Shallow,
+}
+
+fn unescape_pointer_token(token: &str) -> Cow<'_, str> {
+    if token.contains('~') {
+        Cow::Owned(token.replace("~1", "/").replace("~0", "~"))
+    } else {
+        Cow::Borrowed(token)
+    }
+}
+
+// Array indices must not have leading zeros or a sign, as required by RFC 6901.
+fn parse_pointer_index(token: &str) -> Option<usize> {
+    if token.starts_with('+') || (token.starts_with('0') && token.len() > 1) {
+        return None;
+    }
+
+    token.parse().ok()
+}
+
+fn pointer_set_index(pointer: &str, token: &str, len: usize) -> Result<usize> {
+    let index = if token == "-" {
+        len
+    } else {
+        parse_pointer_index(token).ok_or_else(|| {
+            Error::new(format!(
+                "invalid pointer `{pointer}`: `{token}` is not a valid array index"
+            ))
+        })?
+    };
+
+    if index > len {
+        return Err(Error::new(format!(
+            "invalid pointer `{pointer}`: index {index} is out of range for array of length {len}"
+        )));
+    }
+
+    Ok(index)
+}
+
+fn pointer_into_scalar(pointer: &str, token: &str) -> Error {
+    Error::new(format!(
+        "invalid pointer `{pointer}`: `{token}` cannot be set on a scalar value"
+    ))
+}
+
+impl fmt::Debug for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            return fmt::Display::fmt(self, f);
+        }
+
+        match self {
+            Value::Null => f.write_str("Null"),
+            Value::Bool(b) => f.debug_tuple("Bool").field(b).finish(),
+            Value::Number(n) => f.debug_tuple("Number").field(n).finish(),
+            Value::String(s) => f.debug_tuple("String").field(s).finish(),
+            Value::Array(array) => f.debug_tuple("Array").field(array).finish(),
+            Value::Object(object) => f.debug_tuple("Object").field(object).finish(),
+        }
+    }
+}
+
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Value {
+    fn cmp(&self, other: &Self) -> Ordering {
+        fn type_rank(value: &Value) -> u8 {
+            match value {
+                Value::Null => 0,
+                Value::Bool(_) => 1,
+                Value::Number(_) => 2,
+                Value::String(_) => 3,
+                Value::Array(_) => 4,
+                Value::Object(_) => 5,
+            }
+        }
+
+        fn sorted_entries(object: &Map<String, Value>) -> Vec<(&String, &Value)> {
+            let mut entries: Vec<_> = object.iter().collect();
+            entries.sort_unstable_by_key(|&(key, _)| key);
+            entries
+        }
+
+        match (self, other) {
+            (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+            (Value::Number(a), Value::Number(b)) => a.cmp(b),
+            (Value::String(a), Value::String(b)) => a.cmp(b),
+            (Value::Array(a), Value::Array(b)) => a.cmp(b),
+            // Object equality does not depend on the key order, so the ordering must not either.
+            (Value::Object(a), Value::Object(b)) => sorted_entries(a).cmp(&sorted_entries(b)),
+            (a, b) => type_rank(a).cmp(&type_rank(b)),
+        }
+    }
 }
 
 impl fmt::Display for Value {
@@ -211,6 +859,27 @@ impl fmt::Display for Value {
     }
 }
 
+impl FromStr for Value {
+    type Err = Error;
+
+    /// Deserializes a `Value` from a string of HCL text.
+    ///
+    /// This is equivalent to calling [`hcl::from_str`][crate::from_str] and follows the same
+    /// deserialization rules.
+    ///
+    /// ```
+    /// use hcl::Value;
+    ///
+    /// let value: Value = "foo = [1, 2]".parse()?;
+    ///
+    /// assert_eq!(value, hcl::value!({ foo = [1, 2] }));
+    /// # Ok::<(), hcl::Error>(())
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::from_str(s)
+    }
+}
+
 /// Convert a `T` into `hcl::Value` which is an enum that can represent any valid HCL value.
 ///
 /// # Example
@@ -73,7 +73,7 @@ impl ser::Serializer for ValueSerializer {
     }
 
     fn serialize_f32(self, value: f32) -> Result<Self::Ok> {
-        self.serialize_f64(value as f64)
+        Ok(Number::from_f32(value).map_or(Value::Null, Value::Number))
     }
 
     fn serialize_f64(self, value: f64) -> Result<Self::Ok> {
@@ -88,6 +88,12 @@ impl ser::Serializer for ValueSerializer {
         Ok(Value::String(value.to_owned()))
     }
 
+    #[cfg(feature = "base64")]
+    fn serialize_bytes(self, value: &[u8]) -> Result<Self::Ok> {
+        Ok(Value::String(crate::util::encode_base64(value)))
+    }
+
+    #[cfg(not(feature = "base64"))]
     fn serialize_bytes(self, value: &[u8]) -> Result<Self::Ok> {
         let vec = value.iter().map(|&b| Value::Number(b.into())).collect();
         Ok(Value::Array(vec))
@@ -0,0 +1,442 @@
+use super::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn retain_object() {
+    let mut value = Value::from_iter([
+        ("name", Value::from("app")),
+        ("secret", Value::from("hunter2")),
+        (
+            "nested",
+            Value::from_iter([("secret", Value::from("s3cr3t")), ("empty", Value::Null)]),
+        ),
+    ]);
+
+    value.retain_object(|key, _| key != "secret");
+
+    if let Some(nested) = value
+        .as_object_mut()
+        .and_then(|object| object.get_mut("nested"))
+    {
+        nested.retain_object(|_, value| !value.is_null());
+    }
+
+    let expected = Value::from_iter([
+        ("name", Value::from("app")),
+        (
+            "nested",
+            Value::from_iter([("secret", Value::from("s3cr3t"))]),
+        ),
+    ]);
+
+    assert_eq!(value, expected);
+}
+
+#[test]
+fn retain_array() {
+    let mut value = Value::from_iter([
+        Value::from_iter([Value::Null, Value::from(1)]),
+        Value::Null,
+        Value::from(2),
+    ]);
+
+    value.retain_array(|value| !value.is_null());
+
+    if let Some(nested) = value.as_array_mut().and_then(|array| array.first_mut()) {
+        nested.retain_array(|value| !value.is_null());
+    }
+
+    assert_eq!(
+        value,
+        Value::from_iter([Value::from_iter([Value::from(1)]), Value::from(2)])
+    );
+}
+
+#[test]
+fn retain_non_matching_variant() {
+    let mut value = Value::from("foo");
+    value.retain_object(|_, _| false);
+    value.retain_array(|_| false);
+    assert_eq!(value, Value::from("foo"));
+
+    let mut value = Value::from_iter([1, 2]);
+    value.retain_object(|_, _| false);
+    assert_eq!(value, Value::from_iter([1, 2]));
+}
+
+#[test]
+fn get_index() {
+    let value = Value::from_iter([1, 2, 3]);
+
+    assert_eq!(value.get_index(0), Some(&Value::from(1)));
+    assert_eq!(value.get_index(2), Some(&Value::from(3)));
+    assert_eq!(value.get_index(3), None);
+    assert_eq!(value.get_index(-1), Some(&Value::from(3)));
+    assert_eq!(value.get_index(-3), Some(&Value::from(1)));
+    assert_eq!(value.get_index(-4), None);
+    assert_eq!(value.get_index(isize::MIN), None);
+
+    assert_eq!(Value::Array(Vec::new()).get_index(-1), None);
+    assert_eq!(Value::from("foo").get_index(0), None);
+}
+
+#[test]
+fn map() {
+    let value = Value::from_iter([
+        ("a", Value::from(1)),
+        (
+            "b",
+            Value::from_iter([
+                Value::from(2),
+                Value::from_iter([("c", Value::from(3)), ("d", Value::from("x"))]),
+            ]),
+        ),
+        ("e", Value::Null),
+    ]);
+
+    let mapped = value.map(|value| match value {
+        Value::Number(n) => Value::Number(n * Number::from(10)),
+        Value::Null => Value::Bool(false),
+        other => other,
+    });
+
+    let expected = Value::from_iter([
+        ("a", Value::from(10)),
+        (
+            "b",
+            Value::from_iter([
+                Value::from(20),
+                Value::from_iter([("c", Value::from(30)), ("d", Value::from("x"))]),
+            ]),
+        ),
+        ("e", Value::Bool(false)),
+    ]);
+
+    assert_eq!(mapped, expected);
+}
+
+#[test]
+fn sort_keys() {
+    fn keys(value: &Value) -> Vec<&str> {
+        value
+            .as_object()
+            .map(|object| object.keys().map(String::as_str).collect())
+            .unwrap_or_default()
+    }
+
+    let mut value = crate::value!({
+        c = { z = 1, y = 2 }
+        a = [{ k = 1, j = 2 }, 3, 1]
+        b = null
+    });
+
+    value.sort_keys();
+
+    assert_eq!(keys(&value), ["a", "b", "c"]);
+    let object = value.as_object().unwrap();
+    assert_eq!(keys(&object["c"]), ["y", "z"]);
+
+    let array = object["a"].as_array().unwrap();
+    assert_eq!(keys(&array[0]), ["j", "k"]);
+    assert_eq!(array[1..], [Value::from(3), Value::from(1)]);
+}
+
+#[test]
+fn debug() {
+    let value = crate::value!({ foo = [1, null], bar = { baz = true } });
+
+    assert_eq!(
+        format!("{value:?}"),
+        r#"Object({"foo": Array([Number(Number(1)), Null]), "bar": Object({"baz": Bool(true)})})"#
+    );
+
+    let expected = r#"{
+  "foo" = [
+    1,
+    null
+  ]
+  "bar" = {
+    "baz" = true
+  }
+}"#;
+
+    assert_eq!(format!("{value:#?}"), expected);
+    assert_eq!(format!("{:#?}", Value::from("foo")), r#""foo""#);
+}
+
+#[test]
+fn pointer() {
+    let value = crate::value!({
+        "a/b" = { "c~d" = 1 }
+        list = [{ x = true }, "y"]
+    });
+
+    assert_eq!(value.pointer(""), Some(&value));
+    assert_eq!(value.pointer("/a~1b/c~0d"), Some(&Value::from(1)));
+    assert_eq!(value.pointer("/list/0/x"), Some(&Value::from(true)));
+    assert_eq!(value.pointer("/list/1"), Some(&Value::from("y")));
+    assert_eq!(value.pointer("/list/2"), None);
+    assert_eq!(value.pointer("/list/01"), None);
+    assert_eq!(value.pointer("/list/1/z"), None);
+    assert_eq!(value.pointer("list"), None);
+}
+
+#[test]
+fn pointer_mut() {
+    let mut value = crate::value!({
+        "a/b" = { "c~d" = 1 }
+        list = [{ x = true }, "y"]
+    });
+
+    *value.pointer_mut("/a~1b/c~0d").unwrap() = Value::from(2);
+    *value.pointer_mut("/list/0/x").unwrap() = Value::from(false);
+    value
+        .pointer_mut("/list")
+        .and_then(Value::as_array_mut)
+        .unwrap()
+        .push(Value::Null);
+
+    assert_eq!(
+        value,
+        crate::value!({
+            "a/b" = { "c~d" = 2 }
+            list = [{ x = false }, "y", null]
+        })
+    );
+
+    assert_eq!(value.pointer_mut("/list/3"), None);
+    assert_eq!(value.pointer_mut("/list/1/z"), None);
+    assert_eq!(value.pointer_mut("list"), None);
+
+    *value.pointer_mut("").unwrap() = Value::Null;
+    assert_eq!(value, Value::Null);
+}
+
+#[test]
+fn pointer_set() {
+    let mut value = crate::value!({ config = { name = "old", ports = [80] } });
+
+    // Overwrite an existing leaf.
+    value.pointer_set("/config/name", "new".into()).unwrap();
+    assert_eq!(value.pointer("/config/name"), Some(&Value::from("new")));
+
+    // Create a new nested path.
+    value
+        .pointer_set("/config/tags/env/name", "prod".into())
+        .unwrap();
+    assert_eq!(
+        value.pointer("/config/tags"),
+        Some(&crate::value!({ env = { name = "prod" } }))
+    );
+
+    // Array semantics: overwrite, append via length or `-`, and vivify into appended elements.
+    value.pointer_set("/config/ports/0", 8080.into()).unwrap();
+    value.pointer_set("/config/ports/1", 443.into()).unwrap();
+    value.pointer_set("/config/ports/-", 22.into()).unwrap();
+    assert_eq!(
+        value.pointer("/config/ports"),
+        Some(&Value::from_iter([8080, 443, 22]))
+    );
+
+    // Null leaves are inserted like any other value.
+    value.pointer_set("/config/rules/x", Value::Null).unwrap();
+    assert_eq!(
+        value.pointer("/config/rules"),
+        Some(&crate::value!({ x = null }))
+    );
+
+    let mut array = Value::from_iter([1]);
+    array.pointer_set("/-/name", "a".into()).unwrap();
+    assert_eq!(
+        array,
+        Value::from_iter([Value::from(1), crate::value!({ name = "a" })])
+    );
+
+    let errors = [
+        ("config/name", "must be empty or start with `/`"),
+        ("/config/name/x", "`x` cannot be set on a scalar value"),
+        (
+            "/config/ports/9",
+            "index 9 is out of range for array of length 3",
+        ),
+        ("/config/ports/x", "`x` is not a valid array index"),
+        ("/config/ports/01", "`01` is not a valid array index"),
+    ];
+
+    for (pointer, msg) in errors {
+        let err = value.clone().pointer_set(pointer, Value::Null).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("invalid pointer `{pointer}`: {msg}")
+        );
+    }
+
+    value.pointer_set("", Value::Null).unwrap();
+    assert_eq!(value, Value::Null);
+}
+
+#[test]
+fn merge() {
+    let base = crate::value!({
+        region = "eu-west-1"
+        tags = { env = "dev", team = "core" }
+        ports = [80]
+        replaced = { a = 1 }
+    });
+
+    let overrides = crate::value!({
+        tags = { env = "prod", owner = "ops" }
+        ports = [443]
+        replaced = "scalar"
+        new = true
+    });
+
+    // Nested objects are merged, scalars and arrays are overwritten.
+    let mut value = base.clone();
+    value.merge(overrides.clone());
+    assert_eq!(
+        value,
+        crate::value!({
+            region = "eu-west-1"
+            tags = { env = "prod", team = "core", owner = "ops" }
+            ports = [443]
+            replaced = "scalar"
+            new = true
+        })
+    );
+
+    // Arrays are appended.
+    let mut value = base.clone();
+    value.merge_with(overrides.clone(), MergeStrategy::AppendArrays);
+    assert_eq!(value.pointer("/ports"), Some(&Value::from_iter([80, 443])));
+    assert_eq!(value.pointer("/tags/team"), Some(&Value::from("core")));
+
+    // Only top-level keys are merged.
+    let mut value = base.clone();
+    value.merge_with(overrides, MergeStrategy::Shallow);
+    assert_eq!(
+        value.pointer("/tags"),
+        Some(&crate::value!({ env = "prod", owner = "ops" }))
+    );
+    assert_eq!(value.pointer("/region"), Some(&Value::from("eu-west-1")));
+
+    // Non-object values are replaced by the right-hand side.
+    let mut value = Value::from(1);
+    value.merge(crate::value!({ a = 1 }));
+    assert_eq!(value, crate::value!({ a = 1 }));
+
+    let mut value = crate::value!({ a = 1 });
+    value.merge(Value::Null);
+    assert_eq!(value, Value::Null);
+}
+
+#[test]
+fn try_as_accessors() {
+    let mut value = crate::value!({
+        null = null
+        bool = true
+        int = (-1)
+        float = 1.5
+        string = "foo"
+        array = [1]
+    });
+
+    let object = value.try_as_object().unwrap();
+    assert!(object["null"].try_as_null().is_ok());
+    assert!(object["bool"].try_as_bool().unwrap());
+    assert_eq!(object["int"].try_as_i64().unwrap(), -1);
+    assert_eq!(
+        object["float"].try_as_number().unwrap(),
+        &Number::from_f64(1.5).unwrap()
+    );
+    assert_eq!(object["string"].try_as_str().unwrap(), "foo");
+    assert_eq!(object["array"].try_as_array().unwrap().len(), 1);
+
+    let tests = [
+        (
+            object["null"].try_as_bool().unwrap_err(),
+            "invalid type: unit value, expected a boolean",
+        ),
+        (
+            object["bool"].try_as_str().unwrap_err(),
+            "invalid type: boolean `true`, expected a string",
+        ),
+        (
+            object["string"].try_as_f64().unwrap_err(),
+            "invalid type: string \"foo\", expected a number",
+        ),
+        (
+            object["int"].try_as_u64().unwrap_err(),
+            "invalid value: integer `-1`, expected a u64",
+        ),
+        (
+            object["float"].try_as_i64().unwrap_err(),
+            "invalid value: floating point `1.5`, expected an i64",
+        ),
+        (
+            object["array"].try_as_object().unwrap_err(),
+            "invalid type: sequence, expected an object",
+        ),
+        (
+            value.try_as_array().unwrap_err(),
+            "invalid type: map, expected an array",
+        ),
+    ];
+
+    for (err, expected) in tests {
+        assert_eq!(err.to_string(), expected);
+    }
+
+    value.try_as_object_mut().unwrap()["array"]
+        .try_as_array_mut()
+        .unwrap()
+        .push(Value::from(2));
+    assert_eq!(value.pointer("/array/1"), Some(&Value::from(2)));
+    assert!(value.try_as_array_mut().is_err());
+}
+
+#[test]
+fn ordering() {
+    let mut values = vec![
+        Value::from_iter([("b", 1), ("a", 2)]),
+        Value::from("foo"),
+        Value::from(vec![2, 1]),
+        Value::from(2.5),
+        Value::Null,
+        Value::from(true),
+        Value::from(vec![1, 2, 3]),
+        Value::from(-1),
+        Value::from(""),
+        Value::from(false),
+        Value::from_iter([("a", 1), ("b", 1)]),
+        Value::from(2),
+    ];
+
+    values.sort();
+
+    let expected = vec![
+        Value::Null,
+        Value::from(false),
+        Value::from(true),
+        Value::from(-1),
+        Value::from(2),
+        Value::from(2.5),
+        Value::from(""),
+        Value::from("foo"),
+        Value::from(vec![1, 2, 3]),
+        Value::from(vec![2, 1]),
+        Value::from_iter([("a", 1), ("b", 1)]),
+        Value::from_iter([("b", 1), ("a", 2)]),
+    ];
+
+    assert_eq!(values, expected);
+
+    // The key order of objects does not influence the ordering.
+    assert_eq!(
+        Value::from_iter([("a", 1), ("b", 2)]).cmp(&Value::from_iter([("b", 2), ("a", 1)])),
+        Ordering::Equal
+    );
+
+    // Integers and floats with the same value compare equal.
+    assert_eq!(Value::from(2).cmp(&Value::from(2.0)), Ordering::Equal);
+}
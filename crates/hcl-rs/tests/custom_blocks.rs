@@ -8,7 +8,7 @@ use hcl::{
 };
 use indexmap::indexmap;
 use indoc::indoc;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 #[test]
 fn custom_block() {
@@ -283,3 +283,145 @@ fn custom_terraform_blocks() {
 
     assert_serialize(config, expected);
 }
+
+#[test]
+fn custom_optional_blocks() {
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Config {
+        name: String,
+        #[serde(serialize_with = "block", default)]
+        logging: Option<Logging>,
+        #[serde(serialize_with = "labeled_block", default)]
+        backend: Option<Map<String, Logging>>,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Logging {
+        level: String,
+    }
+
+    let config = Config {
+        name: "app".into(),
+        logging: Some(Logging {
+            level: "debug".into(),
+        }),
+        backend: Some(indexmap! {
+            "s3".into() => Logging { level: "info".into() },
+        }),
+    };
+
+    let expected = indoc! {r#"
+        name = "app"
+
+        logging {
+          level = "debug"
+        }
+
+        backend "s3" {
+          level = "info"
+        }
+    "#};
+
+    assert_serialize(&config, expected);
+    assert_eq!(hcl::from_str::<Config>(expected).unwrap(), config);
+
+    let config = Config {
+        name: "app".into(),
+        logging: None,
+        backend: None,
+    };
+
+    let expected = "name = \"app\"\n";
+
+    assert_serialize(&config, expected);
+    assert_eq!(hcl::from_str::<Config>(expected).unwrap(), config);
+}
+
+#[test]
+fn custom_enum_blocks() {
+    #[derive(Serialize)]
+    struct Mirror {
+        url: &'static str,
+    }
+
+    #[derive(Serialize)]
+    #[serde(rename_all = "snake_case")]
+    enum Backend {
+        Local,
+        Port(u16),
+        Remote(Map<&'static str, &'static str>),
+        Mirrors(Mirror, Mirror),
+        S3 { bucket: &'static str },
+    }
+
+    #[derive(Serialize)]
+    struct Config {
+        #[serde(serialize_with = "block")]
+        backend: Backend,
+    }
+
+    assert_serialize(
+        Config {
+            backend: Backend::Local,
+        },
+        indoc! {r#"
+            backend {
+              local {}
+            }
+        "#},
+    );
+
+    assert_serialize(
+        Config {
+            backend: Backend::Port(3),
+        },
+        indoc! {r#"
+            backend {
+              port = 3
+            }
+        "#},
+    );
+
+    assert_serialize(
+        Config {
+            backend: Backend::Remote(indexmap! { "host" => "example.com" }),
+        },
+        indoc! {r#"
+            backend {
+              remote {
+                host = "example.com"
+              }
+            }
+        "#},
+    );
+
+    assert_serialize(
+        Config {
+            backend: Backend::Mirrors(Mirror { url: "a" }, Mirror { url: "b" }),
+        },
+        indoc! {r#"
+            backend {
+              mirrors {
+                url = "a"
+              }
+
+              mirrors {
+                url = "b"
+              }
+            }
+        "#},
+    );
+
+    assert_serialize(
+        Config {
+            backend: Backend::S3 { bucket: "state" },
+        },
+        indoc! {r#"
+            backend {
+              s3 {
+                bucket = "state"
+              }
+            }
+        "#},
+    );
+}
@@ -7,6 +7,7 @@ use hcl::expr::{
 };
 use hcl::structure::{Block, Body};
 use hcl::{Identifier, Value};
+use indoc::indoc;
 use serde::Deserialize;
 use std::fmt::Debug;
 
@@ -455,3 +456,143 @@ fn terraform() {
 
     assert_deserialize(input, expected);
 }
+
+#[test]
+fn deserialize_with_max_structures() {
+    let input = "a = 1\nb = 2\n";
+
+    let options = hcl::ParseOptions::new().max_structures(2);
+    let de = hcl::de::Deserializer::from_str_with_options(input, &options).unwrap();
+    let value = Value::deserialize(de).unwrap();
+    assert_eq!(value, Value::from_iter([("a", 1), ("b", 2)]));
+
+    let options = hcl::ParseOptions::new().max_structures(1);
+    assert!(hcl::de::Deserializer::from_str_with_options(input, &options).is_err());
+}
+
+#[test]
+fn unevaluated_expressions() {
+    let input = indoc! {r#"
+        ami = data.aws_ami.ubuntu.id
+        count = length(var.zones) + 1
+        name = "web-${var.env}"
+    "#};
+
+    assert_deserialize(
+        input,
+        hcl::value!({
+            ami = "${data.aws_ami.ubuntu.id}"
+            count = "${length(var.zones) + 1}"
+            name = "web-${var.env}"
+        }),
+    );
+}
+
+#[test]
+fn body_from_other_formats() {
+    let json = serde_json::json!([
+        { "Attribute": { "key": "a", "expr": { "Bool": true } } },
+        { "Attribute": { "key": "b", "expr": { "Variable": "var" } } },
+        {
+            "Block": {
+                "identifier": "block",
+                "labels": [{ "Identifier": "id" }, { "String": "str" }],
+                "body": [
+                    { "Attribute": { "key": "c", "expr": { "Array": [{ "Number": 1 }, { "Null": null }] } } }
+                ]
+            }
+        }
+    ]);
+
+    let expected = Body::builder()
+        .add_attribute(("a", true))
+        .add_attribute(("b", Variable::unchecked("var")))
+        .add_block(
+            Block::builder("block")
+                .add_label(Identifier::unchecked("id"))
+                .add_label("str")
+                .add_attribute((
+                    "c",
+                    Expression::from_iter([Expression::from(1), Expression::Null]),
+                ))
+                .build(),
+        )
+        .build();
+
+    assert_eq!(serde_json::from_value::<Body>(json).unwrap(), expected);
+}
+
+#[test]
+fn borrowed_strings() {
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Borrowed<'a> {
+        name: &'a str,
+    }
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Cow<'a> {
+        #[serde(borrow)]
+        name: std::borrow::Cow<'a, str>,
+    }
+
+    let input = r#"name = "foo""#;
+
+    assert!(hcl::from_str::<Borrowed>(input).is_err());
+    assert_eq!(
+        hcl::from_str::<Cow>(input).unwrap(),
+        Cow { name: "foo".into() }
+    );
+}
+
+#[test]
+fn multiple_documents() {
+    let input = indoc! {r#"
+        ---
+        a = 1
+        ---
+        b = <<EOT
+        two
+        EOT
+
+        ---
+        c = [
+        ---
+        d = 4
+        ---
+    "#};
+
+    let mut iter = hcl::de::from_str_many::<Value>(input);
+
+    assert_eq!(iter.next().unwrap().unwrap(), hcl::value!({ a = 1 }));
+    assert_eq!(iter.next().unwrap().unwrap(), hcl::value!({ b = "two\n" }));
+    assert!(iter.next().unwrap().is_err());
+    assert_eq!(iter.next().unwrap().unwrap(), hcl::value!({ d = 4 }));
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn multiple_documents_with_separator_in_heredoc() {
+    let input = indoc! {r#"
+        a = <<EOT
+        ---
+        EOT
+        /*
+        ---
+        */
+        b = "---"
+        ---
+        c = 3
+    "#};
+
+    let values = hcl::de::from_str_many::<Value>(input)
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    assert_eq!(
+        values,
+        [
+            hcl::value!({ a = "---\n", b = "---" }),
+            hcl::value!({ c = 3 })
+        ]
+    );
+}
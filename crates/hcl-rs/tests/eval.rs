@@ -36,6 +36,19 @@ fn eval_binary_op() {
         ),
         Value::from(true),
     );
+    assert_eval(BinaryOp::new(7, Mod, 3), Value::from(1));
+    assert_eval_error(
+        BinaryOp::new(1, Div, 0),
+        ErrorKind::BinaryOp(Value::from(1), Div, Value::from(0)),
+    );
+    assert_eval_error(
+        BinaryOp::new(1, Mod, 0),
+        ErrorKind::BinaryOp(Value::from(1), Mod, Value::from(0)),
+    );
+    assert_eval_error(
+        BinaryOp::new(1.5, Mod, 0.0),
+        ErrorKind::BinaryOp(Value::from(1.5), Mod, Value::from(0.0)),
+    );
 }
 
 #[test]
@@ -46,6 +59,17 @@ fn eval_conditional() {
         Conditional::new("foo", "yes", "no"),
         ErrorKind::Unexpected(Value::from("foo"), "a boolean"),
     );
+
+    let mut ctx = Context::new();
+    ctx.declare_var("env", "prod");
+
+    let cond = Conditional::new(
+        BinaryOp::new(Variable::unchecked("env"), BinaryOperator::Eq, "prod"),
+        Variable::unchecked("env"),
+        "dev",
+    );
+
+    assert_eval_ctx(&ctx, cond, Value::from("prod"));
 }
 
 #[test]
@@ -423,3 +447,96 @@ fn interpolation_unwrapping() {
         Value::from("true"),
     );
 }
+
+#[test]
+fn context_from_value() {
+    let vars: Value = hcl::from_str(indoc! {r#"
+        name   = "web"
+        ports  = [80, 443]
+        labels = { env = "prod" }
+    "#})
+    .unwrap();
+
+    let ctx = Context::from_value(vars).unwrap();
+
+    assert_eval_ctx(
+        &ctx,
+        TemplateExpr::from("${name}-${labels.env}:${ports[1]}"),
+        Value::from("web-prod:443"),
+    );
+
+    let err = Context::from_value(Value::from_iter([1, 2])).unwrap_err();
+    assert_eq!(
+        err.kind(),
+        &ErrorKind::Unexpected(Value::from_iter([1, 2]), "an object")
+    );
+}
+
+#[test]
+fn evaluate_const() {
+    let body = hcl::parse(indoc! {r#"
+        arithmetic = 1 + 2 * 3 - 4 / 2
+        negation   = -(2 + 3)
+        logic      = !(true && false) || false
+        comparison = 2 >= 1 == true
+        template   = "${"a"}-${1 + 1}"
+        collection = [for n in [1, 2]: n * 2]
+        variable   = foo + 1
+        function   = upper("a")
+        mismatch   = 1 + true
+    "#})
+    .unwrap();
+
+    let eval = |key: &str| body.get_attribute(key).unwrap().expr().evaluate_const();
+
+    assert_eq!(eval("arithmetic").unwrap(), Value::from(5));
+    assert_eq!(eval("negation").unwrap(), Value::from(-5));
+    assert_eq!(eval("logic").unwrap(), Value::Bool(true));
+    assert_eq!(eval("comparison").unwrap(), Value::Bool(true));
+    assert_eq!(eval("template").unwrap(), Value::from("a-2"));
+    assert_eq!(eval("collection").unwrap(), Value::from_iter([2, 4]));
+
+    for key in ["variable", "function", "mismatch"] {
+        assert!(
+            matches!(eval(key), Err(hcl::Error::Eval(_))),
+            "`{key}` should fail to evaluate"
+        );
+    }
+}
+
+#[test]
+fn render_template_expr() {
+    let vars = hcl::Map::from([
+        ("name".to_owned(), Value::from("web")),
+        ("count".to_owned(), Value::from(2)),
+        ("tags".to_owned(), hcl::value!({ env = "prod" })),
+    ]);
+
+    let tests = [
+        ("${name}-${count}", "web-2"),
+        ("${tags.env}", "prod"),
+        ("${count}", "2"),
+        ("%{ if count > 1 }many%{ else }one%{ endif }", "many"),
+        ("no interpolation", "no interpolation"),
+    ];
+
+    for (template, expected) in tests {
+        assert_eq!(
+            TemplateExpr::from(template).render(&vars).unwrap(),
+            expected
+        );
+    }
+
+    for template in ["${unknown}", "${upper(name)}", "${1 + true}", "${"] {
+        assert!(
+            TemplateExpr::from(template).render(&vars).is_err(),
+            "{template}"
+        );
+    }
+
+    let heredoc = TemplateExpr::from(hcl::expr::Heredoc::new(
+        Identifier::unchecked("EOT"),
+        "name=${name}\n",
+    ));
+    assert_eq!(heredoc.render(&vars).unwrap(), "name=web\n");
+}
@@ -0,0 +1,2 @@
+block {
+  attr = 1
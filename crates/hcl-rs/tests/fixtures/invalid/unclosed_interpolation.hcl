@@ -0,0 +1 @@
+attr = "${var.name"
@@ -0,0 +1,2 @@
+attr = <<EOT
+content
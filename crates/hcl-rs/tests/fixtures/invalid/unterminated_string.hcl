@@ -0,0 +1 @@
+attr = "unterminated
@@ -0,0 +1,5 @@
+upper = [for s in var.list : upper(s)]
+filtered = [for k, v in var.map : v if v != null]
+grouped = { for s in var.list : substr(s, 0, 1) => s... }
+nested = [for outer in var.outer : [for inner in outer : inner * 2]]
+objects = { for k, v in { a = 1, b = 2 } : k => { for x in [v] : tostring(x) => x } }
@@ -0,0 +1,14 @@
+plain = <<EOT
+hello ${name}
+%{ if enabled }on%{ else }off%{ endif }
+EOT
+
+indented = <<-EOT
+    first line
+      nested ${var.value}
+    last line
+  EOT
+
+escaped = <<EOT
+literal $${not_interpolated} and %%{ not_a_directive }
+EOT
@@ -0,0 +1,14 @@
+computed = {
+  (var.key) = "value"
+  "${var.prefix}-name" = 1
+  bare = true
+  "quoted" = false
+}
+
+nested = {
+  a = {
+    b = [1, 2, { c = null }]
+  }
+}
+
+colon = { a : 1, b : 2 }
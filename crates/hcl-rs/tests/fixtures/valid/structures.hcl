@@ -0,0 +1,29 @@
+resource "aws_instance" "web" {
+  ami           = data.aws_ami.ubuntu.id
+  instance_type = var.large ? "m5.large" : "t3.micro"
+  count         = length(var.zones) * 2 + 1
+
+  tags = {
+    Name = "web-${count.index}"
+  }
+
+  lifecycle {
+    create_before_destroy = true
+  }
+
+  dynamic "ingress" {
+    for_each = var.ports
+    content {
+      from_port = ingress.value
+    }
+  }
+}
+
+locals {
+  splat   = aws_instance.web[*].id
+  legacy  = aws_instance.web.*.id
+  index   = var.list[0].name
+  neg     = -1.5e3
+  not     = !var.enabled
+  args    = concat(var.a, var.b...)
+}
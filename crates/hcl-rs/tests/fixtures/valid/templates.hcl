@@ -0,0 +1,5 @@
+greeting = "Hello, ${var.name}!"
+escaped = "literal $${var.name} and %%{ if true }"
+directive = "%{ for x in var.list }${x},%{ endfor }"
+strip = "a ${~ var.b ~} c"
+nested = "outer ${"inner ${var.x}"}"
@@ -5,9 +5,9 @@ use hcl::expr::{
     BinaryOp, BinaryOperator, Conditional, Expression, ForExpr, FuncCall, Heredoc,
     HeredocStripMode, Traversal, TraversalOperator, Variable,
 };
-use hcl::format::Formatter;
+use hcl::format::{Format, Formatter, QuoteStyle};
 use hcl::template::{ForDirective, IfDirective, StripMode, Template};
-use hcl::Identifier;
+use hcl::{Body, Identifier, ParseOptions};
 use indoc::indoc;
 
 #[test]
@@ -106,6 +106,26 @@ fn func_call_expand_final() {
     );
 }
 
+#[test]
+fn func_call_expand_final_roundtrip() {
+    let input = "merged = merge(a, b...)\n";
+
+    let expected = Body::builder()
+        .add_attribute((
+            "merged",
+            FuncCall::builder("merge")
+                .arg(Variable::unchecked("a"))
+                .arg(Variable::unchecked("b"))
+                .expand_final(true)
+                .build(),
+        ))
+        .build();
+
+    let body = hcl::parse(input).unwrap();
+    assert_eq!(body, expected);
+    assert_format(body, input);
+}
+
 #[test]
 fn for_list_expr() {
     assert_format(
@@ -283,3 +303,584 @@ fn traversal() {
 fn empty_block() {
     assert_format(hcl::block!(empty {}), "empty {}\n");
 }
+
+#[test]
+fn empty_body() {
+    assert_format(Body::default(), "");
+
+    assert_format_builder(
+        Formatter::builder().trailing_newline(true),
+        Body::default(),
+        "\n",
+    );
+
+    assert_format_builder(
+        Formatter::builder().trailing_newline(true),
+        hcl::body!({ block {} }),
+        "block {}\n",
+    );
+}
+
+#[test]
+fn owned_indent() {
+    let body = hcl::body!({ block { nested { foo = "bar" } } });
+
+    assert_format_builder(
+        Formatter::builder().indent_spaces(4),
+        body.clone(),
+        "block {\n    nested {\n        foo = \"bar\"\n    }\n}\n",
+    );
+
+    assert_format_builder(
+        Formatter::builder().indent_tab(),
+        body,
+        "block {\n\tnested {\n\t\tfoo = \"bar\"\n\t}\n}\n",
+    );
+}
+
+#[test]
+fn align_attributes() {
+    let body = hcl::body!({
+        a = 1
+        long_key = { x = 1, long = 2 }
+        block {
+            foo = "bar"
+            foobar = "baz"
+            nested {}
+            b = true
+        }
+        abc = 3
+    });
+
+    let expected = indoc! {r#"
+        a        = 1
+        long_key = {
+          x = 1
+          long = 2
+        }
+
+        block {
+          foo    = "bar"
+          foobar = "baz"
+
+          nested {}
+
+          b = true
+        }
+
+        abc = 3
+    "#};
+
+    assert_format_builder(Formatter::builder().align_attributes(true), body, expected);
+}
+
+#[test]
+fn empty_blocks() {
+    let body = hcl::body!({
+        outer {}
+        block {
+            nested {}
+        }
+    });
+
+    let expected = indoc! {r#"
+        outer {}
+
+        block {
+          nested {}
+        }
+    "#};
+
+    assert_format(body, expected);
+}
+
+#[test]
+fn invalid_utf8_indent() {
+    let mut formatter = Formatter::builder().indent(b"\xff\xfe").build_vec();
+    let body = hcl::body!({ block { foo = "bar" } });
+
+    assert!(body.format_string(&mut formatter).is_err());
+}
+
+#[test]
+fn invalid_utf8_assignment() {
+    let mut formatter = Formatter::builder().assignment(b"\xff").build_vec();
+    let body = hcl::body!({ foo = 1 });
+
+    assert!(body.format_string(&mut formatter).is_err());
+}
+
+#[test]
+fn block_hooks() {
+    let body = hcl::body!({
+        attr = 1
+        first { nested { foo = "bar" } }
+        second "label" {}
+    });
+
+    let expected = indoc! {r#"
+        attr = 1
+
+        # begin first
+        first {
+          nested {
+            foo = "bar"
+          }
+        }
+        # end first
+
+        # begin second
+        second "label" {}
+        # end second
+    "#};
+
+    assert_format_builder(
+        Formatter::builder()
+            .before_block(|block, writer| writeln!(writer, "# begin {}", block.identifier()))
+            .after_block(|block, writer| writeln!(writer, "# end {}", block.identifier())),
+        body,
+        expected,
+    );
+}
+
+#[test]
+fn block_hooks_invalid_utf8() {
+    let mut formatter = Formatter::builder()
+        .before_block(|_, writer| writer.write_all(b"\xff\n"))
+        .build_vec();
+    let body = hcl::body!({ block {} });
+
+    assert!(body.format_string(&mut formatter).is_err());
+}
+
+#[test]
+fn block_labels() {
+    let block = hcl::Block::builder("resource")
+        .add_identifier_label("aws_instance")
+        .add_string_label("web")
+        .build();
+
+    assert_format(block, "resource aws_instance \"web\" {}\n");
+}
+
+#[test]
+fn max_line_width() {
+    let script = "#!/bin/sh\necho \"hello world\"\n";
+    let body = hcl::body!({
+        block {
+            script = (script)
+            long = "a very long string without trailing newline"
+            short = "ok\n"
+        }
+    });
+
+    assert_format(
+        &body,
+        indoc! {r##"
+            block {
+              script = "#!/bin/sh\necho \"hello world\"\n"
+              long = "a very long string without trailing newline"
+              short = "ok\n"
+            }
+        "##},
+    );
+
+    assert_format_builder(
+        Formatter::builder().max_line_width(Some(30)),
+        &body,
+        indoc! {r##"
+            block {
+              script = <<EOT
+            #!/bin/sh
+            echo "hello world"
+            EOT
+              long = "a very long string without trailing newline"
+              short = "ok\n"
+            }
+        "##},
+    );
+
+    let body = hcl::body!({ text = "EOT\nEOT1\n" });
+
+    assert_format_builder(
+        Formatter::builder().max_line_width(Some(10)),
+        &body,
+        "text = <<EOT2\nEOT\nEOT1\nEOT2\n",
+    );
+
+    let mut formatter = Formatter::builder().max_line_width(Some(10)).build_vec();
+    let formatted = body.format_string(&mut formatter).unwrap();
+    assert_eq!(
+        hcl::Value::from(hcl::parse(&formatted).unwrap()),
+        hcl::value!({ text = "EOT\nEOT1\n" })
+    );
+}
+
+#[test]
+fn multiline_heredocs() {
+    let value = hcl::value!({
+        policy = "{\n  \"Version\": \"2012-10-17\"\n}\n"
+        no_trailing_newline = "a\nb"
+        single_line = "ok"
+        nested = { script = "a\n" }
+    });
+
+    let mut serializer = hcl::ser::Serializer::with_formatter(
+        Formatter::builder().multiline_heredocs(true).build_vec(),
+    );
+
+    let expected = indoc! {r#"
+        policy = <<EOT
+        {
+          "Version": "2012-10-17"
+        }
+        EOT
+        no_trailing_newline = "a\nb"
+        single_line = "ok"
+        nested = {
+          "script" = "a\n"
+        }
+    "#};
+
+    assert_eq!(serializer.serialize_string(&value).unwrap(), expected);
+    assert_eq!(hcl::from_str::<hcl::Value>(expected).unwrap(), value);
+}
+
+#[test]
+fn quote_object_keys() {
+    let body = hcl::body!({
+        object = {
+            "foo" = 1
+            "foo-bar" = 2
+            "123" = 3
+        }
+    });
+
+    assert_format_builder(
+        Formatter::builder().quote_object_keys(QuoteStyle::Always),
+        &body,
+        indoc! {r#"
+            object = {
+              "foo" = 1
+              "foo-bar" = 2
+              "123" = 3
+            }
+        "#},
+    );
+
+    assert_format_builder(
+        Formatter::builder().quote_object_keys(QuoteStyle::WhenNeeded),
+        body,
+        indoc! {r#"
+            object = {
+              foo = 1
+              foo-bar = 2
+              "123" = 3
+            }
+        "#},
+    );
+}
+
+#[test]
+fn write_bom() {
+    let body = hcl::body!({
+        foo = "bar"
+        block {
+            baz = 1
+        }
+    });
+
+    let mut formatter = Formatter::builder().write_bom(true).build_vec();
+
+    let bytes = body.format_vec(&mut formatter).unwrap();
+    assert!(bytes.starts_with(b"\xEF\xBB\xBFfoo = \"bar\"\n"));
+    assert_eq!(bytes.windows(3).filter(|w| w == b"\xEF\xBB\xBF").count(), 1);
+
+    // The BOM is written again if the formatter is reused.
+    let string = body.format_string(&mut formatter).unwrap();
+    assert!(string.starts_with('\u{feff}'));
+
+    // Bodies written to the same writer share a single BOM at the start.
+    let mut buf = Vec::new();
+    let mut formatter = Formatter::builder().write_bom(true).build(&mut buf);
+    body.format(&mut formatter).unwrap();
+    body.format(&mut formatter).unwrap();
+    assert!(buf.starts_with(b"\xEF\xBB\xBFfoo"));
+    assert_eq!(buf.windows(3).filter(|w| w == b"\xEF\xBB\xBF").count(), 1);
+
+    let mut serializer =
+        hcl::ser::Serializer::with_formatter(Formatter::builder().write_bom(true).build_vec());
+    let bytes = serializer
+        .serialize_vec(&hcl::value!({ foo = "bar" }))
+        .unwrap();
+    assert_eq!(bytes, b"\xEF\xBB\xBFfoo = \"bar\"\n");
+
+    let mut formatter = Formatter::builder().write_bom(false).build_vec();
+    let bytes = body.format_vec(&mut formatter).unwrap();
+    assert!(bytes.starts_with(b"foo"));
+}
+
+#[test]
+fn nested_indented_heredoc() {
+    let body = hcl::body!({
+        outer {
+            inner "label" {
+                script = (
+                    Heredoc::new(Identifier::unchecked("EOT"), "echo foo\n\nif true; then\n  echo bar\nfi\n")
+                        .with_strip_mode(HeredocStripMode::Indent)
+                )
+            }
+        }
+    });
+
+    let expected = indoc! {r#"
+        outer {
+          inner "label" {
+            script = <<-EOT
+              echo foo
+
+              if true; then
+                echo bar
+              fi
+            EOT
+          }
+        }
+    "#};
+
+    assert_format(&body, expected);
+
+    // Re-indenting does not change the value of the heredoc.
+    let parsed: Body = hcl::parse(expected).unwrap();
+    assert_eq!(parsed, body);
+    assert_format(parsed, expected);
+}
+
+#[test]
+fn collapse_blank_lines() {
+    let input =
+        "a = 1\n\n\n\nb = 2\n\n\n\nblock {\n\n\n\n  c = 3\n\n\n\n  nested {\n    d = 4\n  }\n}\n\n\n\nother {}\n";
+    let body: Body = hcl::parse(input).unwrap();
+
+    assert_format(
+        &body,
+        indoc! {r#"
+            a = 1
+            b = 2
+
+            block {
+              c = 3
+
+              nested {
+                d = 4
+              }
+            }
+
+            other {}
+        "#},
+    );
+
+    assert_format_builder(
+        Formatter::builder().dense(true),
+        &body,
+        indoc! {r#"
+            a = 1
+            b = 2
+            block {
+              c = 3
+              nested {
+                d = 4
+              }
+            }
+            other {}
+        "#},
+    );
+}
+
+#[test]
+fn escape_template_sequences() {
+    let tests = [
+        ("${x}", r#""$${x}""#),
+        ("%{ if x }", r#""%%{ if x }""#),
+        ("${${", r#""$${$${""#),
+        ("%{%{", r#""%%{%%{""#),
+        ("$${x}", r#""$$${x}""#),
+        ("$ {} %", r#""$ {} %""#),
+    ];
+
+    let builder = || Formatter::builder().escape_template_sequences(true);
+    let options = ParseOptions::new().unescape_template_sequences(true);
+
+    for (string, formatted) in tests {
+        let body = Body::builder().add_attribute(("s", string)).build();
+        let expected = format!("s = {formatted}\n");
+
+        assert_format_builder(builder(), &body, &expected);
+        assert_eq!(hcl::parse_with_options(&expected, &options).unwrap(), body);
+
+        let value = hcl::value!({ s = (string) });
+        let mut buf = Vec::new();
+        let mut ser = hcl::ser::Serializer::with_formatter(builder().build(&mut buf));
+        ser.serialize(&value).unwrap();
+        assert_eq!(String::from_utf8(buf).unwrap(), expected);
+
+        let parsed = hcl::parse_with_options(&expected, &options).unwrap();
+        assert_eq!(hcl::from_body::<hcl::Value>(parsed).unwrap(), value);
+    }
+
+    // Strings containing template sequences are written verbatim by default.
+    assert_format(
+        Body::builder().add_attribute(("s", "${x}")).build(),
+        "s = \"${x}\"\n",
+    );
+    assert_eq!(
+        hcl::parse("s = \"$${x}\"\n").unwrap(),
+        Body::builder().add_attribute(("s", "$${x}")).build()
+    );
+
+    // Object keys and block labels are escaped as well.
+    let body = hcl::body!({
+        block "${label}" {
+            object = { "${key}" = "%{value}" }
+        }
+    });
+
+    let expected = indoc! {r#"
+        block "$${label}" {
+          object = {
+            "$${key}" = "%%{value}"
+          }
+        }
+    "#};
+
+    assert_format_builder(builder(), &body, expected);
+    assert_eq!(hcl::parse_with_options(expected, &options).unwrap(), body);
+}
+
+#[test]
+fn object_attrs_as_blocks() {
+    let body = hcl::body!({
+        name = "web"
+        ports = [80, 443]
+        tags = {
+            env = "prod"
+            owner = { team = "infra" }
+        }
+        labels = { "not an ident" = true }
+        empty = {}
+        resource "aws_instance" "web" {
+            metadata = { role = "frontend" }
+        }
+    });
+
+    let expected = indoc! {r#"
+        name = "web"
+        ports = [
+          80,
+          443
+        ]
+
+        tags {
+          env = "prod"
+
+          owner {
+            team = "infra"
+          }
+        }
+
+        labels = {
+          "not an ident" = true
+        }
+        empty = {}
+
+        resource "aws_instance" "web" {
+          metadata {
+            role = "frontend"
+          }
+        }
+    "#};
+
+    assert_format_builder(
+        Formatter::builder().object_attrs_as_blocks(true),
+        &body,
+        expected,
+    );
+
+    #[derive(serde::Serialize)]
+    struct Config {
+        name: &'static str,
+        ports: Vec<u16>,
+        tags: hcl::Map<String, &'static str>,
+    }
+
+    let config = Config {
+        name: "web",
+        ports: vec![80],
+        tags: [("env".to_owned(), "prod")].into_iter().collect(),
+    };
+
+    let mut buf = Vec::new();
+    let formatter = Formatter::builder()
+        .object_attrs_as_blocks(true)
+        .compact_arrays(true)
+        .build(&mut buf);
+    let mut ser = hcl::ser::Serializer::with_formatter(formatter);
+    ser.serialize(&config).unwrap();
+
+    assert_eq!(
+        String::from_utf8(buf).unwrap(),
+        "name = \"web\"\nports = [80]\n\ntags {\n  env = \"prod\"\n}\n"
+    );
+
+    let blocks = std::cell::Cell::new(0);
+    let mut formatter = Formatter::builder()
+        .object_attrs_as_blocks(true)
+        .before_block(|_, _| {
+            blocks.set(blocks.get() + 1);
+            Ok(())
+        })
+        .build_vec();
+    body.format_string(&mut formatter).unwrap();
+    assert_eq!(blocks.get(), 1);
+}
+
+#[test]
+fn custom_assignment() {
+    let body = hcl::body!({
+        name = "web"
+        tags = { env = "prod", tier = { level = 1 } }
+        block {
+            enabled = true
+        }
+    });
+
+    assert_format_builder(
+        Formatter::builder().assignment(b" : "),
+        &body,
+        indoc! {r#"
+            name : "web"
+            tags : {
+              env : "prod"
+              tier : {
+                level : 1
+              }
+            }
+
+            block {
+              enabled : true
+            }
+        "#},
+    );
+
+    assert_format_builder(
+        Formatter::builder().assignment(b": ").compact_objects(true),
+        &body,
+        indoc! {r#"
+            name: "web"
+            tags: { env: "prod", tier: { level: 1 } }
+
+            block {
+              enabled: true
+            }
+        "#},
+    );
+}
@@ -0,0 +1,61 @@
+use hcl::Body;
+use pretty_assertions::assert_eq;
+use std::ffi::OsStr;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn fixtures(kind: &str) -> Vec<PathBuf> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(kind);
+
+    let mut paths: Vec<_> = fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension() == Some(OsStr::new("hcl")))
+        .collect();
+
+    paths.sort();
+    assert!(!paths.is_empty(), "no {kind} fixtures found");
+    paths
+}
+
+#[test]
+fn valid_fixtures_roundtrip() {
+    for path in fixtures("valid") {
+        let input = fs::read_to_string(&path).unwrap();
+
+        let body: Body = hcl::parse(&input)
+            .unwrap_or_else(|err| panic!("failed to parse {}: {err}", path.display()));
+
+        let formatted = hcl::format::to_string(&body).unwrap();
+
+        let reparsed: Body = hcl::parse(&formatted).unwrap_or_else(|err| {
+            panic!(
+                "failed to re-parse formatted {}: {err}\n{formatted}",
+                path.display()
+            )
+        });
+
+        assert_eq!(reparsed, body, "{} is not stable", path.display());
+
+        // Formatting is idempotent.
+        assert_eq!(hcl::format::to_string(&reparsed).unwrap(), formatted);
+    }
+}
+
+#[test]
+fn invalid_fixtures_error() {
+    for path in fixtures("invalid") {
+        let input = fs::read_to_string(&path).unwrap();
+
+        match hcl::parse(&input) {
+            Ok(body) => panic!("expected {} to fail, got {body:?}", path.display()),
+            Err(err) => assert!(
+                err.location().is_some(),
+                "expected positioned error for {}, got: {err}",
+                path.display()
+            ),
+        }
+    }
+}
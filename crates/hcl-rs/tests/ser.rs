@@ -199,3 +199,440 @@ fn identifiers_with_hyphens() {
         "hyphen-ated = null\n",
     );
 }
+
+#[test]
+#[cfg(feature = "base64")]
+fn bytes_base64_roundtrip() {
+    #[derive(serde::Serialize, serde::Deserialize, PartialEq, Debug)]
+    struct Test {
+        #[serde(with = "serde_bytes")]
+        data: Vec<u8>,
+    }
+
+    let value = Test {
+        data: b"hello world".to_vec(),
+    };
+
+    let serialized = hcl::to_string(&value).unwrap();
+    assert_eq!(serialized, "data = \"aGVsbG8gd29ybGQ=\"\n");
+    assert_eq!(hcl::from_str::<Test>(&serialized).unwrap(), value);
+
+    let value = hcl::to_value(&value).unwrap();
+    assert_eq!(value, hcl::value!({ data = "aGVsbG8gd29ybGQ=" }));
+    assert!(hcl::from_str::<Test>("data = \"!!\"").is_err());
+}
+
+#[test]
+fn numbers() {
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct Numbers {
+        neg: i32,
+        max_u64: u64,
+        min_i64: i64,
+        float: f64,
+    }
+
+    let numbers = Numbers {
+        neg: -1,
+        max_u64: u64::MAX,
+        min_i64: i64::MIN,
+        float: 1.0,
+    };
+
+    let expected = indoc! {r#"
+        neg = -1
+        max_u64 = 18446744073709551615
+        min_i64 = -9223372036854775808
+        float = 1.0
+    "#};
+
+    assert_serialize(&numbers, expected);
+
+    let value = hcl::to_value(&numbers).unwrap();
+    let object = value.as_object().unwrap();
+    assert_eq!(object["max_u64"].as_u64(), Some(u64::MAX));
+    assert_eq!(object["min_i64"].as_i64(), Some(i64::MIN));
+    assert!(object["float"].as_number().unwrap().is_f64());
+
+    assert_eq!(hcl::from_str::<Numbers>(expected).unwrap(), numbers);
+}
+
+#[test]
+fn bytes_written() {
+    let mut buf = Vec::new();
+    let mut ser = hcl::ser::Serializer::new(&mut buf);
+    assert_eq!(ser.bytes_written(), 0);
+
+    ser.serialize(&hcl::value!({ foo = "bar\n\"baz\"" }))
+        .unwrap();
+    ser.serialize(&hcl::body!({ block { qux = [1, 2] } }))
+        .unwrap();
+
+    let written = ser.bytes_written();
+    assert_eq!(written, buf.len());
+    assert!(written > 0);
+}
+
+#[test]
+fn preserve_source_key_order() {
+    let input = indoc! {r#"
+        zone = "b"
+        region = "eu-west-1"
+        tags = {
+          "Name" = "web"
+          "Environment" = "prod"
+          "App" = "shop"
+        }
+        ami = "ami-123"
+    "#};
+
+    let value: hcl::Value = hcl::from_str(input).unwrap();
+    let keys: Vec<_> = value.as_object().unwrap().keys().collect();
+    assert_eq!(keys, ["zone", "region", "tags", "ami"]);
+    assert_eq!(hcl::to_string(&value).unwrap(), input);
+
+    let body: hcl::Body = hcl::from_str(input).unwrap();
+    assert_eq!(hcl::to_string(&body).unwrap(), input);
+}
+
+#[test]
+fn deterministic_key_order() {
+    let map: std::collections::HashMap<String, u64> =
+        (0..32).map(|i| (format!("key{i:02}"), i)).collect();
+
+    let mut value = hcl::Value::from_iter([("map", hcl::to_value(&map).unwrap())]);
+    value.sort_keys();
+
+    let expected = hcl::to_string(&value).unwrap();
+
+    for _ in 0..8 {
+        let map: std::collections::HashMap<String, u64> =
+            (0..32).rev().map(|i| (format!("key{i:02}"), i)).collect();
+
+        let mut value = hcl::Value::from_iter([("map", hcl::to_value(map).unwrap())]);
+        value.sort_keys();
+
+        assert_eq!(hcl::to_string(&value).unwrap(), expected);
+    }
+
+    assert!(expected.find("key00").unwrap() < expected.find("key31").unwrap());
+
+    let btree: std::collections::BTreeMap<&str, u64> = [("b", 2), ("a", 1)].into();
+    assert_serialize(
+        hcl::Value::from_iter([("map", hcl::to_value(btree).unwrap())]),
+        indoc! {r#"
+            map = {
+              "a" = 1
+              "b" = 2
+            }
+        "#},
+    );
+}
+
+#[test]
+fn to_fmt() {
+    struct Config(hcl::Body);
+
+    impl std::fmt::Display for Config {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            hcl::ser::to_fmt(f, &self.0)
+        }
+    }
+
+    let body = hcl::body!({
+        greeting = "héllo wörld ✓"
+        section "ünïcode" {
+          emoji = "🦀"
+        }
+    });
+
+    let config = Config(body.clone());
+
+    assert_eq!(config.to_string(), hcl::format::to_string(&body).unwrap());
+    assert_eq!(
+        format!("{config}"),
+        indoc! {r#"
+            greeting = "héllo wörld ✓"
+
+            section "ünïcode" {
+              emoji = "🦀"
+            }
+        "#}
+    );
+}
+
+#[test]
+fn single_trailing_newline() {
+    #[derive(serde::Serialize)]
+    struct Attrs {
+        foo: u32,
+    }
+
+    #[derive(serde::Serialize)]
+    struct Blocks {
+        block: hcl::ser::Block<Attrs>,
+    }
+
+    let attrs = hcl::to_string(&Attrs { foo: 1 }).unwrap();
+    assert_eq!(attrs, "foo = 1\n");
+
+    let blocks = hcl::to_string(&Blocks {
+        block: hcl::ser::Block::new(Attrs { foo: 1 }),
+    })
+    .unwrap();
+    assert_eq!(blocks, "block {\n  foo = 1\n}\n");
+
+    let empty = hcl::to_string(&hcl::Map::<String, u32>::new()).unwrap();
+    assert_eq!(empty, "");
+
+    let mut buf = Vec::new();
+    let formatter = hcl::format::Formatter::builder()
+        .trailing_newline(true)
+        .build(&mut buf);
+    let mut ser = hcl::ser::Serializer::with_formatter(formatter);
+    ser.serialize(&hcl::Map::<String, u32>::new()).unwrap();
+    assert_eq!(buf, b"\n");
+}
+
+#[test]
+fn quote_object_keys_when_needed() {
+    #[derive(serde::Serialize)]
+    struct Resource {
+        tags: std::collections::BTreeMap<&'static str, &'static str>,
+    }
+
+    let resource = Resource {
+        tags: [
+            ("Environment", "prod"),
+            ("cost-center", "42"),
+            ("has space", "x"),
+            ("1st", "y"),
+        ]
+        .into(),
+    };
+
+    let mut buf = Vec::new();
+    let formatter = hcl::format::Formatter::builder()
+        .quote_object_keys(hcl::format::QuoteStyle::WhenNeeded)
+        .build(&mut buf);
+    let mut ser = hcl::ser::Serializer::with_formatter(formatter);
+    ser.serialize(&resource).unwrap();
+
+    assert_eq!(
+        String::from_utf8(buf).unwrap(),
+        indoc! {r#"
+            tags = {
+              "1st" = "y"
+              Environment = "prod"
+              cost-center = "42"
+              "has space" = "x"
+            }
+        "#}
+    );
+
+    // Keys are quoted by default.
+    assert!(hcl::to_string(&resource)
+        .unwrap()
+        .contains("\"Environment\" = \"prod\""));
+}
+
+#[test]
+fn raw_expressions_are_write_only() {
+    let body = hcl::Body::builder()
+        .add_attribute(Attribute::new("count", RawExpression::new("var.instances")))
+        .add_attribute(Attribute::new("name", RawExpression::new("\"${var.x}\"")))
+        .build();
+
+    let expected = indoc! {r#"
+        count = var.instances
+        name = "${var.x}"
+    "#};
+
+    assert_serialize(&body, expected);
+
+    // The parser produces regular expressions from the formatted output.
+    let parsed: hcl::Body = hcl::from_str(expected).unwrap();
+    assert_ne!(parsed, body);
+    assert!(matches!(
+        parsed.attributes().next().unwrap().expr(),
+        Expression::Traversal(_)
+    ));
+    assert_eq!(hcl::to_string(&parsed).unwrap(), expected);
+
+    // Raw expressions round-trip when parsed with the respective option.
+    let options = hcl::ParseOptions::new().raw_expressions(true);
+    assert_eq!(hcl::parse_with_options(expected, &options).unwrap(), body);
+}
+
+#[test]
+fn structure_comments() {
+    let body = hcl::Body::builder()
+        .add_attribute(Attribute::new("region", "us-east-1").with_comment("primary region"))
+        .add_block(
+            hcl::Block::builder("resource")
+                .add_labels(["aws_s3_bucket", "logs"])
+                .comment("Stores access logs.\n\nManaged by terraform.")
+                .add_attribute(Attribute::new("acl", "private").with_comment("never public"))
+                .build(),
+        )
+        .build();
+
+    let expected = indoc! {r#"
+        # primary region
+        region = "us-east-1"
+
+        # Stores access logs.
+        #
+        # Managed by terraform.
+        resource "aws_s3_bucket" "logs" {
+          # never public
+          acl = "private"
+        }
+    "#};
+
+    assert_serialize(&body, expected);
+}
+
+#[test]
+fn escape_special_characters_in_strings() {
+    let tests = [
+        ("a\"b", r#""a\"b""#),
+        ("a\\b", r#""a\\b""#),
+        ("a\nb", r#""a\nb""#),
+        ("a\rb", r#""a\rb""#),
+        ("a\tb", r#""a\tb""#),
+    ];
+
+    for (s, expected) in tests {
+        let body = hcl::Body::from(Attribute::new("x", s));
+        let output = hcl::to_string(&body).unwrap();
+        assert_eq!(output, format!("x = {expected}\n"));
+
+        let parsed: hcl::Body = hcl::from_str(&output).unwrap();
+        assert_eq!(parsed, body, "round-trip of {s:?}");
+
+        let value: hcl::Value = hcl::from_str(&output).unwrap();
+        assert_eq!(value, hcl::value!({ x = (s) }));
+    }
+
+    // Template sequences are only escaped if requested.
+    let tests = [
+        ("${var.a}", r#""$${var.a}""#),
+        ("%{ if true }", r#""%%{ if true }""#),
+        ("$${escaped}", r#""$$${escaped}""#),
+    ];
+
+    let options = hcl::ParseOptions::new().unescape_template_sequences(true);
+
+    for (s, expected) in tests {
+        let body = hcl::Body::from(Attribute::new("x", s));
+        let mut formatter = hcl::format::Formatter::builder()
+            .escape_template_sequences(true)
+            .build_vec();
+        let output = hcl::format::Format::format_string(&body, &mut formatter).unwrap();
+        assert_eq!(output, format!("x = {expected}\n"));
+
+        let parsed = hcl::parse_with_options(&output, &options).unwrap();
+        assert_eq!(parsed, body, "round-trip of {s:?}");
+    }
+}
+
+#[test]
+fn serialize_f32() {
+    #[derive(serde::Serialize)]
+    struct Config {
+        scale: f32,
+        offsets: Vec<f32>,
+    }
+
+    let config = Config {
+        scale: 0.1,
+        offsets: vec![1.5, -0.3],
+    };
+
+    assert_serialize(
+        config,
+        indoc! {r#"
+            scale = 0.1
+            offsets = [
+              1.5,
+              -0.3
+            ]
+        "#},
+    );
+
+    assert_eq!(hcl::to_value(0.1f32).unwrap(), hcl::Value::from(0.1f64));
+}
+
+#[test]
+fn splat_roundtrip() {
+    use hcl::expr::{Traversal, Variable};
+
+    let var = Variable::unchecked("var");
+
+    let body = hcl::body!({
+        full = (Traversal::builder(var.clone()).attr("list").full_splat().attr("id").build())
+        attr = (Traversal::builder(var).attr("list").attr_splat().attr("id").build())
+    });
+
+    let expected = indoc! {r#"
+        full = var.list[*].id
+        attr = var.list.*.id
+    "#};
+
+    assert_serialize(&body, expected);
+    assert_eq!(hcl::parse(expected).unwrap(), body);
+}
+
+#[test]
+fn expression_object_keys() {
+    use hcl::expr::{Object, ObjectKey, Traversal, Variable};
+
+    #[derive(serde::Serialize)]
+    struct Test {
+        tags: Object<ObjectKey, Expression>,
+    }
+
+    let key = ObjectKey::Expression(Expression::Parenthesis(Box::new(Expression::from(
+        Traversal::builder(Variable::unchecked("local"))
+            .attr("k")
+            .build(),
+    ))));
+
+    let value = Test {
+        tags: Object::from([(key, Expression::from(1))]),
+    };
+
+    let expected = indoc! {r#"
+        tags = {
+          (local.k) = 1
+        }
+    "#};
+
+    assert_serialize(&value, expected);
+
+    let body = hcl::parse(expected).unwrap();
+    let tags = body.get_attribute("tags").unwrap().expr();
+    assert_eq!(tags, &Expression::from(value.tags));
+}
+
+#[test]
+fn serialize_with_formatter() {
+    use hcl::format::Formatter;
+
+    #[derive(serde::Serialize)]
+    struct Test {
+        list: Vec<u32>,
+    }
+
+    let value = Test { list: vec![1, 2] };
+
+    assert_eq!(
+        hcl::to_string_with(&value, Formatter::builder().compact_arrays(true)).unwrap(),
+        "list = [1, 2]\n"
+    );
+
+    let mut buf = Vec::new();
+    hcl::to_writer_with(&mut buf, &value, Formatter::builder().indent_tab()).unwrap();
+    assert_eq!(String::from_utf8(buf).unwrap(), "list = [\n\t1,\n\t2\n]\n");
+}